@@ -0,0 +1,286 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A declarative conformance-test runner for ADBC drivers.
+//!
+//! Rather than hand-writing a `PatchableDriver`-style mock for every test
+//! (see `tests/test_implement.rs`), driver authors can write a JSON script
+//! of [Command]s and run it against any [AdbcDriver] with [run_script]. This
+//! gives an instant compliance check without re-deriving the FFI plumbing
+//! for each new assertion.
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, StringArray};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::record_batch::RecordBatch;
+use serde::Deserialize;
+
+use crate::driver_manager::{AdbcConnection, AdbcDriver};
+use crate::interface::{ConnectionApi, StatementApi};
+use crate::options::{INGEST_OPTION_MODE, INGEST_OPTION_MODE_CREATE, INGEST_OPTION_TARGET_TABLE};
+
+/// A single step of a conformance script.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum Command {
+    /// Call `ConnectionApi::set_option` on the current connection.
+    SetOption { key: String, value: String },
+    /// Call `ConnectionApi::get_table_schema` and compare the field names
+    /// (types are not compared, since drivers may report slightly different
+    /// but compatible Arrow types for the same SQL type).
+    GetTableSchema {
+        catalog: Option<String>,
+        db_schema: Option<String>,
+        table: String,
+        expect_fields: Vec<String>,
+    },
+    /// Call `ConnectionApi::get_table_types` and compare the result exactly.
+    GetTableTypes { expect: Vec<String> },
+    /// Run `sql` via a new statement's `execute`, optionally comparing the
+    /// resulting schema's field names and/or the concatenated row count.
+    Execute {
+        sql: String,
+        expect_schema: Option<Vec<String>>,
+        expect_rows: Option<usize>,
+    },
+    /// Bulk-ingest `values` as a single Utf8 `column` into a freshly created
+    /// `table`, via [INGEST_OPTION_TARGET_TABLE] +
+    /// `StatementApi::bind_data`/`execute_update` (the same path
+    /// [crate::driver_manager::AdbcStatement::execute_ingest] applies its
+    /// coalesced inserts through).
+    Ingest {
+        table: String,
+        column: String,
+        values: Vec<String>,
+    },
+    /// Call `ConnectionApi::commit`.
+    Commit,
+    /// Call `ConnectionApi::rollback`.
+    Rollback,
+    /// Assert that the *previous* command returned an error whose message
+    /// contains `substring`.
+    ExpectError { substring: String },
+}
+
+/// A full conformance script: an ordered list of [Command]s.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Script {
+    pub commands: Vec<Command>,
+}
+
+impl Script {
+    /// Parse a script from its JSON text representation.
+    pub fn from_json(text: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(text)
+    }
+}
+
+/// Where in a [Script] (and why) a conformance run failed.
+#[derive(Debug)]
+pub struct ConformanceFailure {
+    /// The zero-based index of the command that failed.
+    pub command_index: usize,
+    /// A description of the expected vs. actual result.
+    pub diff: String,
+}
+
+impl std::fmt::Display for ConformanceFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "command #{}: {}", self.command_index, self.diff)
+    }
+}
+
+impl std::error::Error for ConformanceFailure {}
+
+/// Build a database/connection from `driver` and run `script` against it,
+/// returning the first [ConformanceFailure] encountered (if any).
+pub fn run_script(driver: &AdbcDriver, script: &Script) -> Result<(), ConformanceFailure> {
+    let database = driver
+        .new_database()
+        .and_then(|b| b.init())
+        .map_err(|e| ConformanceFailure {
+            command_index: 0,
+            diff: format!("failed to create database: {e}"),
+        })?;
+    let connection = database
+        .new_connection()
+        .and_then(|b| b.init())
+        .map_err(|e| ConformanceFailure {
+            command_index: 0,
+            diff: format!("failed to create connection: {e}"),
+        })?;
+    run_script_on(&connection, script)
+}
+
+fn run_script_on(connection: &AdbcConnection, script: &Script) -> Result<(), ConformanceFailure> {
+    let mut last_error: Option<String> = None;
+
+    for (index, command) in script.commands.iter().enumerate() {
+        let result = match command {
+            Command::ExpectError { .. } => Ok(None),
+            _ => run_one(connection, command),
+        };
+        match (&result, command) {
+            (_, Command::ExpectError { substring }) => {
+                let Some(message) = &last_error else {
+                    return Err(ConformanceFailure {
+                        command_index: index,
+                        diff: "expected the previous command to have failed, but it succeeded"
+                            .to_string(),
+                    });
+                };
+                if !message.contains(substring.as_str()) {
+                    return Err(ConformanceFailure {
+                        command_index: index,
+                        diff: format!(
+                            "expected error message to contain {substring:?}, got {message:?}"
+                        ),
+                    });
+                }
+            }
+            (Err(diff), _) => {
+                return Err(ConformanceFailure {
+                    command_index: index,
+                    diff: diff.clone(),
+                })
+            }
+            (Ok(message), _) => last_error = message.clone(),
+        }
+    }
+    Ok(())
+}
+
+/// Run a single non-`ExpectError` command, returning `Ok(Some(message))` if
+/// the command itself produced a driver error (stashed for a subsequent
+/// `ExpectError` to check), `Ok(None)` on a clean success, or `Err(diff)` if
+/// an assertion in the command itself failed.
+#[allow(clippy::result_large_err)]
+fn run_one(connection: &AdbcConnection, command: &Command) -> Result<Option<String>, String> {
+    match command {
+        Command::SetOption { key, value } => Ok(connection
+            .set_option(key, value)
+            .err()
+            .map(|e| e.to_string())),
+        Command::GetTableSchema {
+            catalog,
+            db_schema,
+            table,
+            expect_fields,
+        } => {
+            let schema = match connection.get_table_schema(
+                catalog.as_deref(),
+                db_schema.as_deref(),
+                table,
+            ) {
+                Ok(schema) => schema,
+                Err(e) => return Ok(Some(e.to_string())),
+            };
+            let actual_fields: Vec<String> =
+                schema.fields().iter().map(|f: &Field| f.name().clone()).collect();
+            if &actual_fields != expect_fields {
+                return Err(format!(
+                    "expected fields {expect_fields:?}, got {actual_fields:?}"
+                ));
+            }
+            Ok(None)
+        }
+        Command::GetTableTypes { expect } => {
+            let table_types = match connection.get_table_types() {
+                Ok(t) => t,
+                Err(e) => return Ok(Some(e.to_string())),
+            };
+            if &table_types != expect {
+                return Err(format!("expected table types {expect:?}, got {table_types:?}"));
+            }
+            Ok(None)
+        }
+        Command::Execute {
+            sql,
+            expect_schema,
+            expect_rows,
+        } => {
+            let outcome: Result<(Option<Schema>, usize), crate::driver_manager::Error> =
+                (|| {
+                    let mut statement = connection.new_statement()?;
+                    statement.set_sql_query(sql)?;
+                    let result = statement.execute()?;
+                    match result.result {
+                        Some(reader) => {
+                            let schema = reader.schema().as_ref().clone();
+                            let batches: Vec<RecordBatch> = reader
+                                .collect::<std::result::Result<_, _>>()
+                                .map_err(|_| {
+                                    crate::driver_manager::Error::new(
+                                        "failed to collect result batches",
+                                        crate::error::AdbcStatusCode::IO,
+                                    )
+                                })?;
+                            let rows = batches.iter().map(|b| b.num_rows()).sum();
+                            Ok((Some(schema), rows))
+                        }
+                        None => Ok((None, 0)),
+                    }
+                })();
+
+            let (schema, rows) = match outcome {
+                Ok(v) => v,
+                Err(e) => return Ok(Some(e.to_string())),
+            };
+
+            if let Some(expect_schema) = expect_schema {
+                let actual_fields: Vec<String> = schema
+                    .as_ref()
+                    .map(|s| s.fields().iter().map(|f| f.name().clone()).collect())
+                    .unwrap_or_default();
+                if &actual_fields != expect_schema {
+                    return Err(format!(
+                        "expected result schema {expect_schema:?}, got {actual_fields:?}"
+                    ));
+                }
+            }
+            if let Some(expect_rows) = expect_rows {
+                if rows != *expect_rows {
+                    return Err(format!("expected {expect_rows} rows, got {rows}"));
+                }
+            }
+            Ok(None)
+        }
+        Command::Ingest {
+            table,
+            column,
+            values,
+        } => {
+            let schema = Arc::new(Schema::new(vec![Field::new(column, DataType::Utf8, true)]));
+            let array: ArrayRef = Arc::new(StringArray::from(values.clone()));
+            let batch = match RecordBatch::try_new(schema, vec![array]) {
+                Ok(batch) => batch,
+                Err(e) => return Err(format!("failed to build ingest batch: {e}")),
+            };
+            let outcome: Result<i64, crate::driver_manager::Error> = (|| {
+                let mut statement = connection.new_statement()?;
+                statement.set_option(INGEST_OPTION_TARGET_TABLE, table)?;
+                statement.set_option(INGEST_OPTION_MODE, INGEST_OPTION_MODE_CREATE)?;
+                statement.bind_data(batch)?;
+                statement.execute_update()
+            })();
+            Ok(outcome.err().map(|e| e.to_string()))
+        }
+        Command::Commit => Ok(connection.commit().err().map(|e| e.to_string())),
+        Command::Rollback => Ok(connection.rollback().err().map(|e| e.to_string())),
+        Command::ExpectError { .. } => unreachable!("handled by the caller"),
+    }
+}
@@ -0,0 +1,526 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Bridges an ADBC connection into DataFusion's catalog and query
+//! execution machinery, so ADBC-backed tables can be queried and joined
+//! alongside any other DataFusion [TableProvider] (e.g. local Parquet).
+//!
+//! [AdbcTableProvider] scans a whole table in one go; [streaming] provides a
+//! `tokio`-gated alternative that streams each of a statement's partitions
+//! as its own DataFusion partition.
+//!
+//! This module requires the `datafusion` feature.
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::datatypes::SchemaRef;
+use async_trait::async_trait;
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::catalog::schema::SchemaProvider;
+use datafusion::catalog::CatalogProvider;
+use datafusion::datasource::{TableProvider, TableType};
+use datafusion::error::{DataFusionError, Result as DFResult};
+use datafusion::execution::context::SessionState;
+use datafusion::logical_expr::Expr;
+use datafusion::physical_plan::memory::MemoryExec;
+use datafusion::physical_plan::ExecutionPlan;
+
+use crate::driver_manager::AdbcConnection;
+use crate::ffi::AdbcObjectDepth;
+use crate::interface::{ConnectionApi, StatementApi};
+use crate::objects::CatalogCollection;
+
+fn df_err(err: crate::driver_manager::Error) -> DataFusionError {
+    DataFusionError::External(format!("{err}").into())
+}
+
+/// A DataFusion [TableProvider] backed by a single ADBC table.
+///
+/// Scans are implemented by pushing a `SELECT` down to
+/// [StatementApi::execute]; there is no further predicate pushdown beyond
+/// picking the queried columns, consistent with how this crate otherwise
+/// always executes whole-statement SQL.
+pub struct AdbcTableProvider {
+    connection: AdbcConnection,
+    catalog: Option<String>,
+    db_schema: Option<String>,
+    table_name: String,
+    schema: SchemaRef,
+}
+
+impl AdbcTableProvider {
+    /// Look up `table_name`'s schema via [ConnectionApi::get_table_schema]
+    /// and wrap it as a queryable [TableProvider].
+    pub fn try_new(
+        connection: AdbcConnection,
+        catalog: Option<&str>,
+        db_schema: Option<&str>,
+        table_name: &str,
+    ) -> DFResult<Self> {
+        let schema = connection
+            .get_table_schema(catalog, db_schema, table_name)
+            .map_err(df_err)?;
+        Ok(Self {
+            connection,
+            catalog: catalog.map(str::to_string),
+            db_schema: db_schema.map(str::to_string),
+            table_name: table_name.to_string(),
+            schema: Arc::new(schema),
+        })
+    }
+
+    /// Build the fully-qualified `SELECT` pushed down to the driver for a scan.
+    fn select_sql(&self, projection: Option<&Vec<usize>>) -> String {
+        qualified_select_sql(
+            self.catalog.as_deref(),
+            self.db_schema.as_deref(),
+            &self.table_name,
+            &self.schema,
+            projection,
+        )
+    }
+}
+
+/// Build a `SELECT <columns> FROM <qualified table>` string pushed down to
+/// the driver for a scan, shared by [AdbcTableProvider] and
+/// [AdbcPartitionedTableProvider].
+fn qualified_select_sql(
+    catalog: Option<&str>,
+    db_schema: Option<&str>,
+    table_name: &str,
+    schema: &SchemaRef,
+    projection: Option<&Vec<usize>>,
+) -> String {
+    let columns = match projection {
+        Some(indices) => indices
+            .iter()
+            .map(|&i| schema.field(i).name().clone())
+            .collect::<Vec<_>>()
+            .join(", "),
+        None => "*".to_string(),
+    };
+    let mut qualified_table = String::new();
+    if let Some(catalog) = catalog {
+        qualified_table.push_str(catalog);
+        qualified_table.push('.');
+    }
+    if let Some(db_schema) = db_schema {
+        qualified_table.push_str(db_schema);
+        qualified_table.push('.');
+    }
+    qualified_table.push_str(table_name);
+    format!("SELECT {columns} FROM {qualified_table}")
+}
+
+#[async_trait]
+impl TableProvider for AdbcTableProvider {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::Base
+    }
+
+    async fn scan(
+        &self,
+        _state: &SessionState,
+        projection: Option<&Vec<usize>>,
+        _filters: &[Expr],
+        _limit: Option<usize>,
+    ) -> DFResult<Arc<dyn ExecutionPlan>> {
+        let sql = self.select_sql(projection);
+        let mut statement = self.connection.new_statement().map_err(df_err)?;
+        statement.set_sql_query(&sql).map_err(df_err)?;
+        let result = statement.execute().map_err(df_err)?;
+        let batches: Vec<RecordBatch> = match result.result {
+            Some(reader) => reader
+                .collect::<std::result::Result<_, _>>()
+                .map_err(|err: arrow::error::ArrowError| DataFusionError::ArrowError(err, None))?,
+            None => vec![],
+        };
+
+        let (schema, projection) = match (projection, batches.first()) {
+            (Some(_), Some(first)) => (first.schema(), None),
+            _ => (self.schema.clone(), projection.cloned()),
+        };
+        let exec = MemoryExec::try_new(&[batches], schema, projection)?;
+        Ok(Arc::new(exec))
+    }
+}
+
+/// A DataFusion [SchemaProvider] listing every table reported by
+/// `get_objects` at [AdbcObjectDepth::Tables] for one `db_schema`.
+pub struct AdbcSchemaProvider {
+    connection: AdbcConnection,
+    catalog: Option<String>,
+    db_schema: String,
+    table_names: Vec<String>,
+}
+
+impl AdbcSchemaProvider {
+    pub fn try_new(connection: AdbcConnection, catalog: Option<&str>, db_schema: &str) -> DFResult<Self> {
+        let table_names = list_tables(&connection, catalog, db_schema)?;
+        Ok(Self {
+            connection,
+            catalog: catalog.map(str::to_string),
+            db_schema: db_schema.to_string(),
+            table_names,
+        })
+    }
+}
+
+#[async_trait]
+impl SchemaProvider for AdbcSchemaProvider {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn table_names(&self) -> Vec<String> {
+        self.table_names.clone()
+    }
+
+    async fn table(&self, name: &str) -> Option<Arc<dyn TableProvider>> {
+        if !self.table_names.iter().any(|t| t == name) {
+            return None;
+        }
+        AdbcTableProvider::try_new(
+            self.connection.clone(),
+            self.catalog.as_deref(),
+            Some(&self.db_schema),
+            name,
+        )
+        .ok()
+        .map(|provider| Arc::new(provider) as Arc<dyn TableProvider>)
+    }
+
+    fn table_exist(&self, name: &str) -> bool {
+        self.table_names.iter().any(|t| t == name)
+    }
+}
+
+/// A DataFusion [CatalogProvider] exposing every ADBC `db_schema` within one
+/// catalog, as reported by `get_objects`.
+pub struct AdbcCatalogProvider {
+    connection: AdbcConnection,
+    catalog: Option<String>,
+    schema_names: Vec<String>,
+}
+
+impl AdbcCatalogProvider {
+    pub fn try_new(connection: AdbcConnection, catalog: Option<&str>) -> DFResult<Self> {
+        let schema_names = list_db_schemas(&connection, catalog)?;
+        Ok(Self {
+            connection,
+            catalog: catalog.map(str::to_string),
+            schema_names,
+        })
+    }
+}
+
+impl CatalogProvider for AdbcCatalogProvider {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema_names(&self) -> Vec<String> {
+        self.schema_names.clone()
+    }
+
+    fn schema(&self, name: &str) -> Option<Arc<dyn SchemaProvider>> {
+        if !self.schema_names.iter().any(|s| s == name) {
+            return None;
+        }
+        AdbcSchemaProvider::try_new(self.connection.clone(), self.catalog.as_deref(), name)
+            .ok()
+            .map(|provider| Arc::new(provider) as Arc<dyn SchemaProvider>)
+    }
+}
+
+/// Walk `get_objects` at [AdbcObjectDepth::DBSchemas] to list the schemas in
+/// `catalog` (or across all catalogs, if `None`).
+fn list_db_schemas(connection: &AdbcConnection, catalog: Option<&str>) -> DFResult<Vec<String>> {
+    let reader = connection
+        .get_objects(AdbcObjectDepth::DBSchemas, catalog, None, None, None, None)
+        .map_err(df_err)?;
+    let collection = CatalogCollection::try_from_reader(reader)
+        .map_err(|err| DataFusionError::ArrowError(err, None))?;
+    Ok(collection
+        .catalogs
+        .into_iter()
+        .flat_map(|catalog| catalog.schemas)
+        .filter_map(|schema| schema.db_schema_name)
+        .collect())
+}
+
+/// Walk `get_objects` at [AdbcObjectDepth::Tables] to list the tables in
+/// `db_schema`.
+fn list_tables(
+    connection: &AdbcConnection,
+    catalog: Option<&str>,
+    db_schema: &str,
+) -> DFResult<Vec<String>> {
+    let reader = connection
+        .get_objects(
+            AdbcObjectDepth::Tables,
+            catalog,
+            Some(db_schema),
+            None,
+            None,
+            None,
+        )
+        .map_err(df_err)?;
+    let collection = CatalogCollection::try_from_reader(reader)
+        .map_err(|err| DataFusionError::ArrowError(err, None))?;
+    Ok(collection
+        .catalogs
+        .into_iter()
+        .flat_map(|catalog| catalog.schemas)
+        .flat_map(|schema| schema.tables)
+        .map(|table| table.table_name)
+        .collect())
+}
+
+/// A streaming, partitioned DataFusion adapter built on
+/// [crate::driver_manager::r#async], as opposed to [AdbcTableProvider]'s
+/// single-partition, eagerly-collected scan.
+///
+/// Gated behind the `tokio` feature, matching
+/// [crate::driver_manager::r#async].
+#[cfg(feature = "tokio")]
+pub mod streaming {
+    use std::any::Any;
+    use std::sync::Arc;
+
+    use arrow::datatypes::SchemaRef;
+    use async_trait::async_trait;
+    use datafusion::datasource::{TableProvider, TableType};
+    use datafusion::error::{DataFusionError, Result as DFResult};
+    use datafusion::execution::context::SessionState;
+    use datafusion::execution::TaskContext;
+    use datafusion::logical_expr::Expr;
+    use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
+    use datafusion::physical_plan::{
+        DisplayAs, DisplayFormatType, ExecutionPlan, Partitioning, PlanProperties,
+        SendableRecordBatchStream,
+    };
+    use futures::{StreamExt, TryStreamExt};
+
+    use crate::driver_manager::r#async::AsyncConnection;
+    use crate::error::Error;
+
+    use super::qualified_select_sql;
+
+    fn df_err(err: Error) -> DataFusionError {
+        DataFusionError::External(format!("{err}").into())
+    }
+
+    /// A DataFusion [TableProvider] backed by an ADBC table, scanned through
+    /// [AsyncConnection] so driving the query never blocks the executor, and
+    /// surfacing each partition from [crate::interface::StatementApi::execute_partitioned]
+    /// as its own DataFusion partition rather than collecting everything
+    /// up front like [super::AdbcTableProvider] does.
+    ///
+    /// Requires the driver to support
+    /// [crate::interface::StatementApi::execute_partitioned]; scanning a
+    /// driver that doesn't returns whatever error it reports (typically
+    /// `NotImplemented`).
+    pub struct AdbcPartitionedTableProvider {
+        connection: AsyncConnection,
+        catalog: Option<String>,
+        db_schema: Option<String>,
+        table_name: String,
+        schema: SchemaRef,
+    }
+
+    impl AdbcPartitionedTableProvider {
+        pub async fn try_new(
+            connection: AsyncConnection,
+            catalog: Option<&str>,
+            db_schema: Option<&str>,
+            table_name: &str,
+        ) -> DFResult<Self> {
+            let schema = connection
+                .get_table_schema(
+                    catalog.map(str::to_string),
+                    db_schema.map(str::to_string),
+                    table_name.to_string(),
+                )
+                .await
+                .map_err(df_err)?;
+            Ok(Self {
+                connection,
+                catalog: catalog.map(str::to_string),
+                db_schema: db_schema.map(str::to_string),
+                table_name: table_name.to_string(),
+                schema: Arc::new(schema),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl TableProvider for AdbcPartitionedTableProvider {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn schema(&self) -> SchemaRef {
+            self.schema.clone()
+        }
+
+        fn table_type(&self) -> TableType {
+            TableType::Base
+        }
+
+        async fn scan(
+            &self,
+            _state: &SessionState,
+            projection: Option<&Vec<usize>>,
+            _filters: &[Expr],
+            _limit: Option<usize>,
+        ) -> DFResult<Arc<dyn ExecutionPlan>> {
+            let sql = qualified_select_sql(
+                self.catalog.as_deref(),
+                self.db_schema.as_deref(),
+                &self.table_name,
+                &self.schema,
+                projection,
+            );
+            let statement = self.connection.new_statement().await.map_err(df_err)?;
+            statement.set_sql_query(sql).await.map_err(df_err)?;
+            let result = statement.execute_partitioned().await.map_err(df_err)?;
+            Ok(Arc::new(AdbcPartitionedExec::new(
+                self.connection.clone(),
+                result.partition_ids,
+                Arc::new(result.schema),
+            )))
+        }
+    }
+
+    /// A DataFusion [ExecutionPlan] that reads each of an ADBC statement's
+    /// partition IDs as a separate DataFusion partition, pulled lazily
+    /// through [AsyncConnection::read_partition].
+    #[derive(Debug)]
+    pub struct AdbcPartitionedExec {
+        connection: AsyncConnection,
+        partition_ids: Vec<Vec<u8>>,
+        schema: SchemaRef,
+        properties: PlanProperties,
+    }
+
+    impl AdbcPartitionedExec {
+        fn new(connection: AsyncConnection, partition_ids: Vec<Vec<u8>>, schema: SchemaRef) -> Self {
+            let properties = PlanProperties::new(
+                datafusion::physical_expr::EquivalenceProperties::new(schema.clone()),
+                Partitioning::UnknownPartitioning(partition_ids.len().max(1)),
+                datafusion::physical_plan::ExecutionMode::Bounded,
+            );
+            Self {
+                connection,
+                partition_ids,
+                schema,
+                properties,
+            }
+        }
+    }
+
+    impl DisplayAs for AdbcPartitionedExec {
+        fn fmt_as(&self, _t: DisplayFormatType, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(
+                f,
+                "AdbcPartitionedExec: partitions={}",
+                self.partition_ids.len()
+            )
+        }
+    }
+
+    impl ExecutionPlan for AdbcPartitionedExec {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn properties(&self) -> &PlanProperties {
+            &self.properties
+        }
+
+        fn children(&self) -> Vec<&Arc<dyn ExecutionPlan>> {
+            vec![]
+        }
+
+        fn with_new_children(
+            self: Arc<Self>,
+            _children: Vec<Arc<dyn ExecutionPlan>>,
+        ) -> DFResult<Arc<dyn ExecutionPlan>> {
+            Ok(self)
+        }
+
+        fn execute(
+            &self,
+            partition: usize,
+            _context: Arc<TaskContext>,
+        ) -> DFResult<SendableRecordBatchStream> {
+            let partition_id = self.partition_ids[partition].clone();
+            let connection = self.connection.clone();
+            let schema = self.schema.clone();
+            let stream = futures::stream::once(async move {
+                let reader = connection.read_partition(partition_id).await.map_err(df_err)?;
+                Ok::<_, DataFusionError>(
+                    reader
+                        .into_stream()
+                        .map(|batch| batch.map_err(|err| DataFusionError::ArrowError(err, None))),
+                )
+            })
+            .try_flatten();
+            Ok(Box::pin(RecordBatchStreamAdapter::new(schema, stream)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::datatypes::{DataType, Field, Schema};
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+        ]))
+    }
+
+    #[test]
+    fn test_qualified_select_sql_no_projection() {
+        let sql = qualified_select_sql(Some("cat"), Some("sch"), "tbl", &schema(), None);
+        assert_eq!(sql, "SELECT * FROM cat.sch.tbl");
+    }
+
+    #[test]
+    fn test_qualified_select_sql_with_projection() {
+        let sql = qualified_select_sql(None, None, "tbl", &schema(), Some(&vec![1]));
+        assert_eq!(sql, "SELECT name FROM tbl");
+    }
+
+    #[test]
+    fn test_qualified_select_sql_catalog_only() {
+        let sql = qualified_select_sql(Some("cat"), None, "tbl", &schema(), None);
+        assert_eq!(sql, "SELECT * FROM cat.tbl");
+    }
+}
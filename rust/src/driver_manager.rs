@@ -0,0 +1,1850 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! The driver manager wraps [crate::ffi] structs in a safe, ergonomic,
+//! builder-style Rust API, implementing [crate::interface] for whatever
+//! driver was loaded underneath.
+use std::collections::{HashMap, VecDeque};
+use std::ffi::CString;
+use std::fmt;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+use arrow::array::StructArray;
+use arrow::datatypes::Schema;
+use arrow::error::ArrowError;
+use arrow::ffi::FFI_ArrowSchema;
+use arrow::ffi_stream::{ArrowArrayStreamReader, FFI_ArrowArrayStream};
+use arrow::record_batch::{RecordBatch, RecordBatchReader};
+use libloading::{Library, Symbol};
+
+use crate::error::{AdbcError, AdbcStatusCode};
+use crate::ffi::{
+    AdbcObjectDepth, FFI_AdbcConnection, FFI_AdbcDatabase, FFI_AdbcDriver, FFI_AdbcPartitions,
+    FFI_AdbcStatement,
+};
+use crate::implement::AdbcDriverInitFunc;
+use crate::ingest::{ChangeOperation, ChangeStream};
+use crate::interface::{
+    ConnectionApi, DatabaseApi, PartitionedStatementResult, StatementApi, StatementResult,
+};
+
+/// An error surfaced by the driver manager, flattening whatever the
+/// underlying driver reported through the FFI boundary.
+///
+/// Carries the ADBC 1.1.0 error details: `sqlstate`/`vendor_code` are read
+/// back from the driver's [crate::error::FFI_AdbcError], and `details` is
+/// populated via the driver's `error_get_detail` entrypoint, if it
+/// implements one (empty otherwise).
+#[derive(Debug, Clone)]
+pub struct Error {
+    pub message: String,
+    pub status_code: AdbcStatusCode,
+    pub sqlstate: Option<[u8; 5]>,
+    pub vendor_code: Option<i32>,
+    pub details: Vec<(String, Vec<u8>)>,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}: {}", self.status_code, self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl AdbcError for Error {
+    fn message(&self) -> &str {
+        &self.message
+    }
+
+    fn status_code(&self) -> AdbcStatusCode {
+        self.status_code
+    }
+
+    fn sqlstate(&self) -> Option<[u8; 5]> {
+        self.sqlstate
+    }
+
+    fn vendor_code(&self) -> Option<i32> {
+        self.vendor_code
+    }
+
+    fn details(&self) -> Vec<(String, Vec<u8>)> {
+        self.details.clone()
+    }
+}
+
+impl Error {
+    pub(crate) fn new(message: impl Into<String>, status_code: AdbcStatusCode) -> Self {
+        Self {
+            message: message.into(),
+            status_code,
+            sqlstate: None,
+            vendor_code: None,
+            details: Vec::new(),
+        }
+    }
+
+    /// Build an `Error` from a non-`Ok` status returned alongside a
+    /// populated [crate::error::FFI_AdbcError], carrying over the SQLSTATE
+    /// and vendor code the driver reported (if any), plus the ADBC 1.1.0
+    /// detail key/value pairs (read back via `driver`'s `error_get_detail`,
+    /// if it implements that entrypoint).
+    pub(crate) fn from_ffi(
+        error: &crate::error::FFI_AdbcError,
+        status_code: AdbcStatusCode,
+        driver: &FFI_AdbcDriver,
+    ) -> Self {
+        Self {
+            message: unsafe { crate::error::ffi_message_to_string(error.message) }
+                .unwrap_or_else(|| "unknown driver error".to_string()),
+            status_code,
+            sqlstate: error.sqlstate(),
+            vendor_code: error.vendor_code(),
+            details: decode_error_details(driver.error_get_detail, error),
+        }
+    }
+}
+
+/// Walk the `index`-keyed detail entries the driver attached to `error` via
+/// `error_get_detail` (ADBC 1.1.0), stopping at the first null key.
+fn decode_error_details(
+    error_get_detail: Option<
+        unsafe extern "C" fn(
+            *const crate::error::FFI_AdbcError,
+            i32,
+        ) -> crate::error::FFI_AdbcErrorDetail,
+    >,
+    error: &crate::error::FFI_AdbcError,
+) -> Vec<(String, Vec<u8>)> {
+    let Some(error_get_detail) = error_get_detail else {
+        return Vec::new();
+    };
+    let mut details = Vec::new();
+    for index in 0.. {
+        let detail = unsafe { error_get_detail(error as *const _, index) };
+        if detail.key.is_null() {
+            break;
+        }
+        let key = unsafe { crate::error::ffi_message_to_string(detail.key) }.unwrap_or_default();
+        let value = unsafe { std::slice::from_raw_parts(detail.value, detail.value_length) }.to_vec();
+        details.push((key, value));
+    }
+    details
+}
+
+/// The result type returned throughout the driver manager.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The default entrypoint symbol exported by an ADBC driver shared library.
+const DEFAULT_ENTRYPOINT: &str = "AdbcDriverInit";
+
+/// A loaded, initialized driver, used to construct [AdbcDatabaseBuilder]s.
+///
+/// This owns the underlying [FFI_AdbcDriver] vtable and keeps it alive as
+/// long as any database/connection/statement created from it is alive. If
+/// the driver was `dlopen`ed from a shared library, it also keeps the
+/// [Library] handle alive for just as long, since the vtable's function
+/// pointers (including `release`) live inside the loaded code.
+#[derive(Clone)]
+pub struct AdbcDriver {
+    pub(crate) inner: Arc<FFI_AdbcDriver>,
+    _library: Option<Arc<Library>>,
+}
+
+impl AdbcDriver {
+    /// Dynamically load a driver from a shared library by name (e.g.
+    /// `"adbc_driver_sqlite"`) or path, resolving `entrypoint` (or the
+    /// default `AdbcDriverInit` symbol if `None`) and calling it to
+    /// populate the vtable.
+    ///
+    /// If `name` is not itself a path to an existing file, the
+    /// platform-conventional `lib`/extension variants of it are tried (e.g.
+    /// `adbc_driver_sqlite` tries `libadbc_driver_sqlite.so` on Linux).
+    pub fn load(name: &str, entrypoint: Option<&str>, version: i32) -> Result<Self> {
+        let mut last_err = None;
+        let mut library = None;
+        for candidate in candidate_library_names(name) {
+            match unsafe { Library::new(&candidate) } {
+                Ok(lib) => {
+                    library = Some(lib);
+                    break;
+                }
+                Err(e) => last_err = Some((candidate, e)),
+            }
+        }
+        let library = library.ok_or_else(|| {
+            let (candidate, e) = last_err.expect("candidate_library_names is never empty");
+            Error::new(
+                format!("failed to load driver library '{candidate}': {e}"),
+                AdbcStatusCode::IO,
+            )
+        })?;
+
+        let entrypoint = entrypoint.unwrap_or(DEFAULT_ENTRYPOINT);
+        let init_func: Symbol<AdbcDriverInitFunc> =
+            unsafe { library.get(entrypoint.as_bytes()) }.map_err(|e| {
+                Error::new(
+                    format!("failed to resolve entrypoint '{entrypoint}' in driver '{name}': {e}"),
+                    AdbcStatusCode::NotFound,
+                )
+            })?;
+
+        let mut driver = FFI_AdbcDriver::empty(version);
+        let mut error = crate::error::FFI_AdbcError::empty();
+        let status = unsafe {
+            init_func(
+                version,
+                &mut driver as *mut FFI_AdbcDriver as *mut std::ffi::c_void,
+                &mut error,
+            )
+        };
+        if status != AdbcStatusCode::Ok {
+            return Err(Error::from_ffi(&error, status, &driver));
+        }
+        Ok(Self {
+            inner: Arc::new(driver),
+            _library: Some(Arc::new(library)),
+        })
+    }
+
+    /// Build a driver directly from an already-resolved init function, e.g.
+    /// one generated in-process by [crate::adbc_init_func].
+    pub fn load_from_init(init_func: &AdbcDriverInitFunc, version: i32) -> Result<Self> {
+        let mut driver = FFI_AdbcDriver::empty(version);
+        let mut error = crate::error::FFI_AdbcError::empty();
+        let status = unsafe {
+            init_func(
+                version,
+                &mut driver as *mut FFI_AdbcDriver as *mut std::ffi::c_void,
+                &mut error,
+            )
+        };
+        if status != AdbcStatusCode::Ok {
+            return Err(Error::from_ffi(&error, status, &driver));
+        }
+        Ok(Self {
+            inner: Arc::new(driver),
+            _library: None,
+        })
+    }
+
+    /// Start building a new database using this driver.
+    pub fn new_database(&self) -> Result<AdbcDatabaseBuilder> {
+        let mut database = FFI_AdbcDatabase::empty();
+        database.private_driver = self.inner.as_ref() as *const FFI_AdbcDriver;
+        call_driver_fn(&self.inner, self.inner.database_new, &mut database)?;
+        Ok(AdbcDatabaseBuilder {
+            driver: self.clone(),
+            database,
+        })
+    }
+
+    /// If `stream`'s `get_next` reported an error, reconstruct the full
+    /// error (status code, vendor code, SQLSTATE, and detail key/value
+    /// pairs) that produced it, via the ADBC 1.1.0 `error_from_array_stream`
+    /// entrypoint. Returns `None` if the driver doesn't implement it, or if
+    /// it reports that `stream` has no associated error.
+    pub fn error_from_array_stream(
+        &self,
+        stream: *mut arrow::ffi_stream::FFI_ArrowArrayStream,
+    ) -> Option<Error> {
+        let func = self.inner.error_from_array_stream?;
+        let mut status = AdbcStatusCode::Ok;
+        let error = unsafe { func(stream, &mut status) };
+        let error = unsafe { error.as_ref() }?;
+        Some(Error::from_ffi(error, status, &self.inner))
+    }
+}
+
+/// The platform-conventional shared library filenames to try for a driver
+/// named `name`, in order: the bare name first (so an already-qualified
+/// path is tried as-is), then, if no file exists at that path, the
+/// platform-qualified name, e.g. `adbc_driver_sqlite` on Linux then tries
+/// `libadbc_driver_sqlite.so`.
+fn candidate_library_names(name: &str) -> Vec<String> {
+    let mut candidates = vec![name.to_string()];
+    if !std::path::Path::new(name).exists() {
+        #[cfg(target_os = "windows")]
+        candidates.push(format!("{name}.dll"));
+        #[cfg(target_os = "macos")]
+        candidates.push(format!("lib{name}.dylib"));
+        #[cfg(all(unix, not(target_os = "macos")))]
+        candidates.push(format!("lib{name}.so"));
+    }
+    candidates
+}
+
+/// Dynamically loads and caches ADBC drivers by name, so that looking a
+/// driver up under the same name twice returns the same [AdbcDriver]
+/// (without re-`dlopen`ing its shared library).
+///
+/// This mirrors how an ADBC environment object owns loaded driver handles
+/// and hands out connections from them; it is [Send] + [Sync] so it can be
+/// shared across an application, even though the [AdbcDriver]s it hands
+/// out are used to build non-thread-safe connections.
+#[derive(Default)]
+pub struct DriverManager {
+    drivers: Mutex<HashMap<String, AdbcDriver>>,
+}
+
+impl DriverManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the driver registered under `name`, `dlopen`ing
+    /// `library_name` (see [AdbcDriver::load]) and resolving `entrypoint`
+    /// and caching the result under `name` if it is not already loaded.
+    pub fn load(
+        &self,
+        name: &str,
+        library_name: &str,
+        entrypoint: Option<&str>,
+        version: i32,
+    ) -> Result<AdbcDriver> {
+        let mut drivers = self.drivers.lock().unwrap();
+        if let Some(driver) = drivers.get(name) {
+            return Ok(driver.clone());
+        }
+        let driver = AdbcDriver::load(library_name, entrypoint, version)?;
+        drivers.insert(name.to_string(), driver.clone());
+        Ok(driver)
+    }
+
+    /// Look up a driver previously registered via [Self::load].
+    pub fn get(&self, name: &str) -> Option<AdbcDriver> {
+        self.drivers.lock().unwrap().get(name).cloned()
+    }
+}
+
+fn call_driver_fn<T>(
+    driver: &FFI_AdbcDriver,
+    func: Option<unsafe extern "C" fn(*mut T, *mut crate::error::FFI_AdbcError) -> AdbcStatusCode>,
+    arg: &mut T,
+) -> Result<()> {
+    let Some(func) = func else {
+        return Err(Error::new("driver function not implemented", AdbcStatusCode::NotImplemented));
+    };
+    let mut error = crate::error::FFI_AdbcError::empty();
+    let status = unsafe { func(arg as *mut T, &mut error) };
+    if status == AdbcStatusCode::Ok {
+        Ok(())
+    } else {
+        Err(Error::from_ffi(&error, status, driver))
+    }
+}
+
+fn set_option_cstr<T>(
+    driver: &FFI_AdbcDriver,
+    func: Option<
+        unsafe extern "C" fn(
+            *mut T,
+            *const std::ffi::c_char,
+            *const std::ffi::c_char,
+            *mut crate::error::FFI_AdbcError,
+        ) -> AdbcStatusCode,
+    >,
+    target: &mut T,
+    key: &str,
+    value: &str,
+) -> Result<()> {
+    let Some(func) = func else {
+        return Err(Error::new("set_option not implemented", AdbcStatusCode::NotImplemented));
+    };
+    let key = CString::new(key).map_err(|_| Error::new("key contains a NUL byte", AdbcStatusCode::InvalidArgument))?;
+    let value = CString::new(value).map_err(|_| Error::new("value contains a NUL byte", AdbcStatusCode::InvalidArgument))?;
+    let mut error = crate::error::FFI_AdbcError::empty();
+    let status = unsafe { func(target, key.as_ptr(), value.as_ptr(), &mut error) };
+    if status == AdbcStatusCode::Ok {
+        Ok(())
+    } else {
+        Err(Error::from_ffi(&error, status, driver))
+    }
+}
+
+/// Marshal a single C-string argument (e.g. `statement_set_sql_query`).
+fn call_cstr_fn<T>(
+    driver: &FFI_AdbcDriver,
+    func: Option<
+        unsafe extern "C" fn(
+            *mut T,
+            *const std::ffi::c_char,
+            *mut crate::error::FFI_AdbcError,
+        ) -> AdbcStatusCode,
+    >,
+    target: &mut T,
+    value: &str,
+) -> Result<()> {
+    let Some(func) = func else {
+        return Err(Error::new("driver function not implemented", AdbcStatusCode::NotImplemented));
+    };
+    let value = CString::new(value)
+        .map_err(|_| Error::new("value contains a NUL byte", AdbcStatusCode::InvalidArgument))?;
+    let mut error = crate::error::FFI_AdbcError::empty();
+    let status = unsafe { func(target, value.as_ptr(), &mut error) };
+    if status == AdbcStatusCode::Ok {
+        Ok(())
+    } else {
+        Err(Error::from_ffi(&error, status, driver))
+    }
+}
+
+fn arrow_err(err: ArrowError) -> Error {
+    Error::new(err.to_string(), AdbcStatusCode::Internal)
+}
+
+/// A database under construction: options may be set via
+/// [AdbcDatabaseBuilder::set_option] until [AdbcDatabaseBuilder::init] is
+/// called.
+pub struct AdbcDatabaseBuilder {
+    driver: AdbcDriver,
+    database: FFI_AdbcDatabase,
+}
+
+impl AdbcDatabaseBuilder {
+    /// Set an option prior to initializing the database. Consumes and
+    /// returns `self` so options can be chained.
+    pub fn set_option(mut self, key: &str, value: &str) -> Result<Self> {
+        set_option_cstr(
+            &self.driver.inner,
+            self.driver.inner.database_set_option,
+            &mut self.database,
+            key,
+            value,
+        )?;
+        Ok(self)
+    }
+
+    /// Finish initialization, yielding a usable [AdbcDatabase].
+    pub fn init(mut self) -> Result<AdbcDatabase> {
+        call_driver_fn(&self.driver.inner, self.driver.inner.database_init, &mut self.database)?;
+        Ok(AdbcDatabase {
+            driver: self.driver,
+            database: Arc::new(self.database),
+        })
+    }
+}
+
+/// An initialized database. Cheaply [Clone]able; all clones share the same
+/// underlying driver state and are released together once the last handle
+/// is dropped.
+#[derive(Clone)]
+pub struct AdbcDatabase {
+    driver: AdbcDriver,
+    database: Arc<FFI_AdbcDatabase>,
+}
+
+impl DatabaseApi for AdbcDatabase {
+    type Error = Error;
+
+    fn set_option(&self, key: &str, value: &str) -> Result<()> {
+        // Safety: `database` is behind an `Arc`, but the driver contract
+        // only requires exclusive *logical* access, which callers must
+        // uphold themselves (ADBC connections are not required to be
+        // `Sync`).
+        let database = unsafe { &mut *(Arc::as_ptr(&self.database) as *mut FFI_AdbcDatabase) };
+        set_option_cstr(&self.driver.inner, self.driver.inner.database_set_option, database, key, value)
+    }
+}
+
+impl AdbcDatabase {
+    /// Start building a new connection to this database.
+    pub fn new_connection(&self) -> Result<AdbcConnectionBuilder> {
+        let mut connection = FFI_AdbcConnection::empty();
+        connection.private_driver = self.driver.inner.as_ref() as *const FFI_AdbcDriver as *mut FFI_AdbcDriver;
+        let mut error = crate::error::FFI_AdbcError::empty();
+        let status = unsafe {
+            self.driver
+                .inner
+                .connection_new
+                .ok_or(())
+                .map_err(|_| ())
+                .map(|f| f(&mut connection, &mut error))
+                .unwrap_or(AdbcStatusCode::NotImplemented)
+        };
+        if status != AdbcStatusCode::Ok {
+            return Err(Error::from_ffi(&error, status, &self.driver.inner));
+        }
+        Ok(AdbcConnectionBuilder {
+            database: self.clone(),
+            connection,
+        })
+    }
+}
+
+/// A connection under construction.
+pub struct AdbcConnectionBuilder {
+    database: AdbcDatabase,
+    connection: FFI_AdbcConnection,
+}
+
+impl AdbcConnectionBuilder {
+    /// Set an option prior to initializing the connection.
+    pub fn set_option(mut self, key: &str, value: &str) -> Result<Self> {
+        set_option_cstr(
+            &self.database.driver.inner,
+            self.database.driver.inner.connection_set_option,
+            &mut self.connection,
+            key,
+            value,
+        )?;
+        Ok(self)
+    }
+
+    /// Finish initialization against the parent database, yielding a usable
+    /// [AdbcConnection].
+    pub fn init(mut self) -> Result<AdbcConnection> {
+        let mut error = crate::error::FFI_AdbcError::empty();
+        let database_ptr = Arc::as_ptr(&self.database.database) as *mut FFI_AdbcDatabase;
+        let status = unsafe {
+            match self.database.driver.inner.connection_init {
+                Some(f) => f(&mut self.connection, database_ptr, &mut error),
+                None => AdbcStatusCode::NotImplemented,
+            }
+        };
+        if status != AdbcStatusCode::Ok {
+            return Err(Error::from_ffi(&error, status, &self.database.driver.inner));
+        }
+        Ok(AdbcConnection {
+            database: self.database,
+            connection: Rc::new(self.connection),
+        })
+    }
+}
+
+/// An initialized connection to a database.
+#[derive(Clone)]
+pub struct AdbcConnection {
+    database: AdbcDatabase,
+    connection: Rc<FFI_AdbcConnection>,
+}
+
+impl ConnectionApi for AdbcConnection {
+    type Error = Error;
+
+    fn set_option(&self, key: &str, value: &str) -> Result<()> {
+        // Safety: see `AdbcDatabase::set_option` -- `connection` is behind
+        // an `Rc`, but the driver contract only requires exclusive
+        // *logical* access.
+        let connection = unsafe { &mut *(Rc::as_ptr(&self.connection) as *mut FFI_AdbcConnection) };
+        set_option_cstr(
+            &self.database.driver.inner,
+            self.database.driver.inner.connection_set_option,
+            connection,
+            key,
+            value,
+        )
+    }
+
+    fn get_info(&self, info_codes: &[u32]) -> Result<Box<dyn RecordBatchReader>> {
+        let Some(func) = self.database.driver.inner.connection_get_info else {
+            return Err(Error::new(
+                "driver does not implement connection_get_info",
+                AdbcStatusCode::NotImplemented,
+            ));
+        };
+        // Safety: see `AdbcDatabase::set_option` -- `connection` is behind
+        // an `Rc`, but the driver contract only requires exclusive
+        // *logical* access.
+        let connection = unsafe { &mut *(Rc::as_ptr(&self.connection) as *mut FFI_AdbcConnection) };
+        let mut stream = FFI_ArrowArrayStream::empty();
+        let mut error = crate::error::FFI_AdbcError::empty();
+        let status =
+            unsafe { func(connection, info_codes.as_ptr(), info_codes.len(), &mut stream, &mut error) };
+        if status != AdbcStatusCode::Ok {
+            return Err(Error::from_ffi(&error, status, &self.database.driver.inner));
+        }
+        let reader = ArrowArrayStreamReader::try_new(stream).map_err(arrow_err)?;
+        Ok(Box::new(reader))
+    }
+
+    fn get_objects(
+        &self,
+        depth: AdbcObjectDepth,
+        catalog: Option<&str>,
+        db_schema: Option<&str>,
+        table_name: Option<&str>,
+        table_type: Option<&[&str]>,
+        column_name: Option<&str>,
+    ) -> Result<Box<dyn RecordBatchReader>> {
+        let Some(func) = self.database.driver.inner.connection_get_objects else {
+            return Err(Error::new(
+                "driver does not implement connection_get_objects",
+                AdbcStatusCode::NotImplemented,
+            ));
+        };
+        let to_cstr = |s: Option<&str>| -> Result<Option<CString>> {
+            s.map(|s| {
+                CString::new(s)
+                    .map_err(|_| Error::new("value contains a NUL byte", AdbcStatusCode::InvalidArgument))
+            })
+            .transpose()
+        };
+        let catalog = to_cstr(catalog)?;
+        let db_schema = to_cstr(db_schema)?;
+        let table_name = to_cstr(table_name)?;
+        let column_name = to_cstr(column_name)?;
+        // `table_type` is passed as a NULL-terminated array of NUL-terminated
+        // C strings, so we have to keep the owned `CString`s (and the
+        // pointer array built over them) alive across the call.
+        let table_type_cstrs: Option<Vec<CString>> = table_type
+            .map(|types| {
+                types
+                    .iter()
+                    .map(|t| {
+                        CString::new(*t).map_err(|_| {
+                            Error::new("value contains a NUL byte", AdbcStatusCode::InvalidArgument)
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })
+            .transpose()?;
+        let table_type_ptrs: Option<Vec<*const std::ffi::c_char>> =
+            table_type_cstrs.as_ref().map(|cstrs| {
+                cstrs
+                    .iter()
+                    .map(|c| c.as_ptr())
+                    .chain(std::iter::once(std::ptr::null()))
+                    .collect()
+            });
+
+        // Safety: see `AdbcDatabase::set_option` -- `connection` is behind
+        // an `Rc`, but the driver contract only requires exclusive
+        // *logical* access.
+        let connection = unsafe { &mut *(Rc::as_ptr(&self.connection) as *mut FFI_AdbcConnection) };
+        let mut stream = arrow::ffi_stream::FFI_ArrowArrayStream::empty();
+        let mut error = crate::error::FFI_AdbcError::empty();
+        let status = unsafe {
+            func(
+                connection,
+                depth,
+                catalog.as_ref().map_or(std::ptr::null(), |c| c.as_ptr()),
+                db_schema.as_ref().map_or(std::ptr::null(), |c| c.as_ptr()),
+                table_name.as_ref().map_or(std::ptr::null(), |c| c.as_ptr()),
+                table_type_ptrs.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+                column_name.as_ref().map_or(std::ptr::null(), |c| c.as_ptr()),
+                &mut stream,
+                &mut error,
+            )
+        };
+        if status != AdbcStatusCode::Ok {
+            return Err(Error::from_ffi(&error, status, &self.database.driver.inner));
+        }
+        let reader = arrow::ffi_stream::ArrowArrayStreamReader::try_new(stream)
+            .map_err(|err| Error::new(err.to_string(), AdbcStatusCode::Internal))?;
+        Ok(Box::new(reader))
+    }
+
+    fn get_table_schema(
+        &self,
+        catalog: Option<&str>,
+        db_schema: Option<&str>,
+        table_name: &str,
+    ) -> Result<Schema> {
+        let Some(func) = self.database.driver.inner.connection_get_table_schema else {
+            return Err(Error::new(
+                "driver does not implement connection_get_table_schema",
+                AdbcStatusCode::NotImplemented,
+            ));
+        };
+        let to_cstr = |s: &str| -> Result<CString> {
+            CString::new(s)
+                .map_err(|_| Error::new("value contains a NUL byte", AdbcStatusCode::InvalidArgument))
+        };
+        let catalog = catalog.map(to_cstr).transpose()?;
+        let db_schema = db_schema.map(to_cstr).transpose()?;
+        let table_name = to_cstr(table_name)?;
+        // Safety: see `AdbcDatabase::set_option` -- `connection` is behind
+        // an `Rc`, but the driver contract only requires exclusive
+        // *logical* access.
+        let connection = unsafe { &mut *(Rc::as_ptr(&self.connection) as *mut FFI_AdbcConnection) };
+        let mut schema = FFI_ArrowSchema::empty();
+        let mut error = crate::error::FFI_AdbcError::empty();
+        let status = unsafe {
+            func(
+                connection,
+                catalog.as_ref().map_or(std::ptr::null(), |c| c.as_ptr()),
+                db_schema.as_ref().map_or(std::ptr::null(), |c| c.as_ptr()),
+                table_name.as_ptr(),
+                &mut schema,
+                &mut error,
+            )
+        };
+        if status != AdbcStatusCode::Ok {
+            return Err(Error::from_ffi(&error, status, &self.database.driver.inner));
+        }
+        Schema::try_from(&schema).map_err(arrow_err)
+    }
+
+    fn get_table_types(&self) -> Result<Vec<String>> {
+        let Some(func) = self.database.driver.inner.connection_get_table_types else {
+            return Err(Error::new(
+                "driver does not implement connection_get_table_types",
+                AdbcStatusCode::NotImplemented,
+            ));
+        };
+        // Safety: see `AdbcDatabase::set_option` -- `connection` is behind
+        // an `Rc`, but the driver contract only requires exclusive
+        // *logical* access.
+        let connection = unsafe { &mut *(Rc::as_ptr(&self.connection) as *mut FFI_AdbcConnection) };
+        let mut stream = FFI_ArrowArrayStream::empty();
+        let mut error = crate::error::FFI_AdbcError::empty();
+        let status = unsafe { func(connection, &mut stream, &mut error) };
+        if status != AdbcStatusCode::Ok {
+            return Err(Error::from_ffi(&error, status, &self.database.driver.inner));
+        }
+        let reader = ArrowArrayStreamReader::try_new(stream).map_err(arrow_err)?;
+        let mut table_types = Vec::new();
+        for batch in reader {
+            let batch = batch.map_err(arrow_err)?;
+            let Some(column) = batch.column_by_name("table_type") else {
+                continue;
+            };
+            let column = column
+                .as_any()
+                .downcast_ref::<arrow::array::StringArray>()
+                .ok_or_else(|| Error::new("table_type column is not utf8", AdbcStatusCode::Internal))?;
+            table_types.extend((0..column.len()).map(|i| column.value(i).to_string()));
+        }
+        Ok(table_types)
+    }
+
+    fn read_partition(&self, partition: &[u8]) -> Result<Box<dyn RecordBatchReader>> {
+        let Some(func) = self.database.driver.inner.connection_read_partition else {
+            return Err(Error::new(
+                "driver does not implement connection_read_partition",
+                AdbcStatusCode::NotImplemented,
+            ));
+        };
+        // Safety: see `AdbcDatabase::set_option` -- `connection` is behind an
+        // `Rc`, but the driver contract only requires exclusive *logical*
+        // access.
+        let connection = unsafe { &mut *(Rc::as_ptr(&self.connection) as *mut FFI_AdbcConnection) };
+        let mut stream = arrow::ffi_stream::FFI_ArrowArrayStream::empty();
+        let mut error = crate::error::FFI_AdbcError::empty();
+        let status =
+            unsafe { func(connection, partition.as_ptr(), partition.len(), &mut stream, &mut error) };
+        if status != AdbcStatusCode::Ok {
+            return Err(Error::from_ffi(&error, status, &self.database.driver.inner));
+        }
+        let reader = arrow::ffi_stream::ArrowArrayStreamReader::try_new(stream)
+            .map_err(|err| Error::new(err.to_string(), AdbcStatusCode::Internal))?;
+        Ok(Box::new(reader))
+    }
+
+    fn commit(&self) -> Result<()> {
+        // Safety: see `AdbcDatabase::set_option` -- `connection` is behind
+        // an `Rc`, but the driver contract only requires exclusive
+        // *logical* access.
+        let connection = unsafe { &mut *(Rc::as_ptr(&self.connection) as *mut FFI_AdbcConnection) };
+        call_driver_fn(&self.database.driver.inner, self.database.driver.inner.connection_commit, connection)
+    }
+
+    fn rollback(&self) -> Result<()> {
+        // Safety: see `AdbcDatabase::set_option` -- `connection` is behind
+        // an `Rc`, but the driver contract only requires exclusive
+        // *logical* access.
+        let connection = unsafe { &mut *(Rc::as_ptr(&self.connection) as *mut FFI_AdbcConnection) };
+        call_driver_fn(&self.database.driver.inner, self.database.driver.inner.connection_rollback, connection)
+    }
+}
+
+impl AdbcConnection {
+    /// Create a new statement on this connection.
+    pub fn new_statement(&self) -> Result<AdbcStatement> {
+        let Some(func) = self.database.driver.inner.statement_new else {
+            return Err(Error::new(
+                "driver does not implement statement_new",
+                AdbcStatusCode::NotImplemented,
+            ));
+        };
+        let mut statement = FFI_AdbcStatement::empty();
+        statement.private_driver =
+            self.database.driver.inner.as_ref() as *const FFI_AdbcDriver as *mut FFI_AdbcDriver;
+        // Safety: see `AdbcDatabase::set_option` -- `connection` is behind
+        // an `Rc`, but the driver contract only requires exclusive
+        // *logical* access.
+        let connection = unsafe { &mut *(Rc::as_ptr(&self.connection) as *mut FFI_AdbcConnection) };
+        let mut error = crate::error::FFI_AdbcError::empty();
+        let status = unsafe { func(connection, &mut statement, &mut error) };
+        if status != AdbcStatusCode::Ok {
+            return Err(Error::from_ffi(&error, status, &self.database.driver.inner));
+        }
+        Ok(AdbcStatement {
+            connection: self.clone(),
+            statement,
+            ingest: IngestState::default(),
+        })
+    }
+}
+
+/// Local-only bookkeeping for [StatementApi::bind_change_stream] /
+/// [StatementApi::execute_ingest]: `key_columns` and
+/// `max_buffered_versions` are configured via
+/// [StatementApi::set_option] (see [crate::options::INGEST_OPTION_KEY_COLUMNS]
+/// and [crate::options::INGEST_OPTION_MAX_BUFFERED_VERSIONS]) but are never
+/// forwarded to the driver, since they only make sense to the coalescing
+/// logic in this module.
+#[derive(Default)]
+struct IngestState {
+    key_columns: Vec<String>,
+    max_buffered_versions: Option<usize>,
+    pending: Vec<crate::ingest::ChangeBatch>,
+}
+
+/// A statement created from an [AdbcConnection].
+pub struct AdbcStatement {
+    connection: AdbcConnection,
+    statement: FFI_AdbcStatement,
+    ingest: IngestState,
+}
+
+impl StatementApi for AdbcStatement {
+    type Error = Error;
+
+    fn prepare(&mut self) -> Result<()> {
+        let driver = &self.connection.database.driver.inner;
+        call_driver_fn(driver, driver.statement_prepare, &mut self.statement)
+    }
+
+    fn set_option(&mut self, key: &str, value: &str) -> Result<()> {
+        if key == crate::options::INGEST_OPTION_KEY_COLUMNS {
+            self.ingest.key_columns = value.split(',').map(|s| s.to_string()).collect();
+            return Ok(());
+        }
+        if key == crate::options::INGEST_OPTION_MAX_BUFFERED_VERSIONS {
+            let max = value.parse::<usize>().map_err(|_| {
+                Error::new(
+                    "adbc.ingest.max_buffered_versions must be a non-negative integer",
+                    AdbcStatusCode::InvalidArgument,
+                )
+            })?;
+            self.ingest.max_buffered_versions = Some(max);
+            return Ok(());
+        }
+        let driver = &self.connection.database.driver.inner;
+        set_option_cstr(driver, driver.statement_set_option, &mut self.statement, key, value)
+    }
+
+    fn set_sql_query(&mut self, query: &str) -> Result<()> {
+        let driver = &self.connection.database.driver.inner;
+        call_cstr_fn(driver, driver.statement_set_sql_query, &mut self.statement, query)
+    }
+
+    fn set_substrait_plan(&mut self, plan: &[u8]) -> Result<()> {
+        let driver = &self.connection.database.driver.inner;
+        let Some(func) = driver.statement_set_substrait_plan else {
+            return Err(Error::new(
+                "driver does not implement statement_set_substrait_plan",
+                AdbcStatusCode::NotImplemented,
+            ));
+        };
+        let mut error = crate::error::FFI_AdbcError::empty();
+        let status = unsafe { func(&mut self.statement, plan.as_ptr(), plan.len(), &mut error) };
+        if status == AdbcStatusCode::Ok {
+            Ok(())
+        } else {
+            Err(Error::from_ffi(&error, status, driver))
+        }
+    }
+
+    fn get_param_schema(&mut self) -> Result<Schema> {
+        let driver = &self.connection.database.driver.inner;
+        let Some(func) = driver.statement_get_parameter_schema else {
+            return Err(Error::new(
+                "driver does not implement statement_get_parameter_schema",
+                AdbcStatusCode::NotImplemented,
+            ));
+        };
+        let mut schema = FFI_ArrowSchema::empty();
+        let mut error = crate::error::FFI_AdbcError::empty();
+        let status = unsafe { func(&mut self.statement, &mut schema, &mut error) };
+        if status != AdbcStatusCode::Ok {
+            return Err(Error::from_ffi(&error, status, driver));
+        }
+        Schema::try_from(&schema).map_err(arrow_err)
+    }
+
+    fn bind_data(&mut self, batch: RecordBatch) -> Result<()> {
+        let driver = &self.connection.database.driver.inner;
+        let Some(func) = driver.statement_bind else {
+            return Err(Error::new(
+                "driver does not implement statement_bind",
+                AdbcStatusCode::NotImplemented,
+            ));
+        };
+        let struct_array = StructArray::from(batch);
+        let (mut array, mut schema) = arrow::ffi::to_ffi(&struct_array.to_data()).map_err(arrow_err)?;
+        let mut error = crate::error::FFI_AdbcError::empty();
+        let status = unsafe { func(&mut self.statement, &mut array, &mut schema, &mut error) };
+        if status == AdbcStatusCode::Ok {
+            Ok(())
+        } else {
+            Err(Error::from_ffi(&error, status, driver))
+        }
+    }
+
+    fn bind_stream(&mut self, stream: Box<dyn RecordBatchReader>) -> Result<()> {
+        let driver = &self.connection.database.driver.inner;
+        let Some(func) = driver.statement_bind_stream else {
+            return Err(Error::new(
+                "driver does not implement statement_bind_stream",
+                AdbcStatusCode::NotImplemented,
+            ));
+        };
+        let mut ffi_stream = FFI_ArrowArrayStream::new(SendableReader(stream));
+        let mut error = crate::error::FFI_AdbcError::empty();
+        let status = unsafe { func(&mut self.statement, &mut ffi_stream, &mut error) };
+        if status == AdbcStatusCode::Ok {
+            Ok(())
+        } else {
+            Err(Error::from_ffi(&error, status, driver))
+        }
+    }
+
+    fn execute(&mut self) -> Result<StatementResult> {
+        let driver = &self.connection.database.driver.inner;
+        let Some(func) = driver.statement_execute_query else {
+            return Err(Error::new(
+                "driver does not implement statement_execute_query",
+                AdbcStatusCode::NotImplemented,
+            ));
+        };
+        let mut stream = FFI_ArrowArrayStream::empty();
+        let mut rows_affected: i64 = -1;
+        let mut error = crate::error::FFI_AdbcError::empty();
+        let status =
+            unsafe { func(&mut self.statement, &mut stream, &mut rows_affected, &mut error) };
+        if status != AdbcStatusCode::Ok {
+            return Err(Error::from_ffi(&error, status, driver));
+        }
+        let reader = ArrowArrayStreamReader::try_new(stream).map_err(arrow_err)?;
+        Ok(StatementResult {
+            result: Some(Box::new(reader)),
+            rows_affected,
+        })
+    }
+
+    fn execute_update(&mut self) -> Result<i64> {
+        let driver = &self.connection.database.driver.inner;
+        let Some(func) = driver.statement_execute_query else {
+            return Err(Error::new(
+                "driver does not implement statement_execute_query",
+                AdbcStatusCode::NotImplemented,
+            ));
+        };
+        let mut rows_affected: i64 = -1;
+        let mut error = crate::error::FFI_AdbcError::empty();
+        let status = unsafe {
+            func(
+                &mut self.statement,
+                std::ptr::null_mut(),
+                &mut rows_affected,
+                &mut error,
+            )
+        };
+        if status != AdbcStatusCode::Ok {
+            return Err(Error::from_ffi(&error, status, driver));
+        }
+        Ok(rows_affected)
+    }
+
+    fn execute_partitioned(&mut self) -> Result<PartitionedStatementResult> {
+        let driver = &self.connection.database.driver.inner;
+        let Some(func) = driver.statement_execute_partitions else {
+            return Err(Error::new(
+                "driver does not implement statement_execute_partitions",
+                AdbcStatusCode::NotImplemented,
+            ));
+        };
+        let mut schema = FFI_ArrowSchema::empty();
+        let mut partitions = FFI_AdbcPartitions::empty();
+        let mut rows_affected: i64 = -1;
+        let mut error = crate::error::FFI_AdbcError::empty();
+        let status = unsafe {
+            func(
+                &mut self.statement,
+                &mut schema,
+                &mut partitions,
+                &mut rows_affected,
+                &mut error,
+            )
+        };
+        if status != AdbcStatusCode::Ok {
+            return Err(Error::from_ffi(&error, status, driver));
+        }
+        let schema = Schema::try_from(&schema).map_err(arrow_err)?;
+        let partition_ids = partitions.to_vec();
+        // `FFI_AdbcPartitions` has no `Drop` impl of its own (unlike the
+        // other FFI structs in this module) -- the driver contract requires
+        // us to call its embedded `release` callback ourselves once we're
+        // done reading it.
+        if let Some(release) = partitions.release {
+            unsafe { release(&mut partitions) };
+        }
+        Ok(PartitionedStatementResult {
+            schema,
+            partition_ids,
+            rows_affected,
+        })
+    }
+
+    /// Buffers `stream` locally, coalescing it down to the latest version per
+    /// key (see [crate::ingest::coalesce_changes]) once more than
+    /// [crate::options::INGEST_OPTION_MAX_BUFFERED_VERSIONS] rows are
+    /// buffered, to bound memory on a long-running stream. The coalesced
+    /// batches are actually applied by [Self::execute_ingest].
+    fn bind_change_stream(&mut self, stream: ChangeStream) -> Result<()> {
+        self.ingest.pending.extend(stream);
+        if let Some(max) = self.ingest.max_buffered_versions {
+            let buffered: usize = self.ingest.pending.iter().map(|c| c.batch.num_rows()).sum();
+            if buffered > max {
+                let key_columns: Vec<&str> =
+                    self.ingest.key_columns.iter().map(String::as_str).collect();
+                self.ingest.pending = crate::ingest::coalesce_changes(
+                    std::mem::take(&mut self.ingest.pending),
+                    &key_columns,
+                )
+                .map_err(arrow_err)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies the change stream bound via [Self::bind_change_stream] to
+    /// `target_table`, coalescing it down to one [crate::ingest::ChangeBatch]
+    /// per [crate::ingest::ChangeOperation] (only when
+    /// [crate::options::INGEST_OPTION_KEY_COLUMNS] is set; without key
+    /// columns the bound batches are passed through uncoalesced) and applying each via the normal
+    /// bulk-ingestion path ([crate::options::INGEST_OPTION_TARGET_TABLE] +
+    /// [Self::bind_data] + [Self::execute_update]).
+    ///
+    /// ADBC's bulk ingestion only supports creating or appending to a table,
+    /// so there is no driver-level primitive for the `Update`/`Delete` arms
+    /// of [crate::ingest::ChangeOperation] -- those return a `NotImplemented`
+    /// error rather than silently doing nothing.
+    fn execute_ingest(&mut self, target_table: &str) -> Result<i64> {
+        let key_columns: Vec<&str> = self.ingest.key_columns.iter().map(String::as_str).collect();
+        let pending = std::mem::take(&mut self.ingest.pending);
+        let coalesced = crate::ingest::coalesce_changes(pending, &key_columns).map_err(arrow_err)?;
+        let mut rows_affected: i64 = 0;
+        for change in coalesced {
+            match change.operation {
+                ChangeOperation::Insert => {
+                    self.set_option(crate::options::INGEST_OPTION_TARGET_TABLE, target_table)?;
+                    self.set_option(
+                        crate::options::INGEST_OPTION_MODE,
+                        crate::options::INGEST_OPTION_MODE_APPEND,
+                    )?;
+                    self.bind_data(change.batch)?;
+                    let affected = self.execute_update()?;
+                    if affected >= 0 {
+                        rows_affected += affected;
+                    }
+                }
+                ChangeOperation::Update | ChangeOperation::Delete => {
+                    return Err(Error::new(
+                        format!(
+                            "{:?} is not supported by ADBC bulk ingestion, which only supports inserting rows",
+                            change.operation
+                        ),
+                        AdbcStatusCode::NotImplemented,
+                    ));
+                }
+            }
+        }
+        Ok(rows_affected)
+    }
+}
+
+/// [StatementApi::bind_stream] takes a `Box<dyn RecordBatchReader>`, which is
+/// not `Send` in general, but [FFI_ArrowArrayStream::new] requires it. We
+/// only ever pull from it on the thread that calls `get_next`/`release`
+/// across the FFI boundary, so moving it there once is sound (mirrors
+/// `implement::dispatch::SendableReader`).
+struct SendableReader(Box<dyn RecordBatchReader>);
+unsafe impl Send for SendableReader {}
+
+impl Iterator for SendableReader {
+    type Item = std::result::Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+impl RecordBatchReader for SendableReader {
+    fn schema(&self) -> arrow::datatypes::SchemaRef {
+        self.0.schema()
+    }
+}
+
+type ConnectionCustomizer = dyn Fn(&AdbcConnection) -> Result<()> + Send + Sync;
+
+/// Builder for [AdbcConnectionPool], modeled on `r2d2::Pool`'s builder.
+pub struct AdbcConnectionPoolBuilder {
+    database: AdbcDatabase,
+    max_size: u32,
+    acquire_timeout: Duration,
+    idle_timeout: Option<Duration>,
+    test_on_check_out: bool,
+    customizer: Option<Arc<ConnectionCustomizer>>,
+    validator: Option<Arc<ConnectionCustomizer>>,
+    validation_query: Option<String>,
+}
+
+impl AdbcConnectionPoolBuilder {
+    /// Start building a pool of connections to `database`.
+    pub fn new(database: AdbcDatabase) -> Self {
+        Self {
+            database,
+            max_size: 10,
+            acquire_timeout: Duration::from_secs(30),
+            idle_timeout: None,
+            test_on_check_out: true,
+            customizer: None,
+            validator: None,
+            validation_query: None,
+        }
+    }
+
+    /// The maximum number of connections the pool will open.
+    pub fn max_size(mut self, max_size: u32) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// How long [AdbcConnectionPool::acquire] will wait for a connection
+    /// before returning a [AdbcStatusCode::Timeout] error.
+    pub fn acquire_timeout(mut self, acquire_timeout: Duration) -> Self {
+        self.acquire_timeout = acquire_timeout;
+        self
+    }
+
+    /// Discard an idle connection (rather than handing it out) once it has
+    /// sat unused in the pool longer than `idle_timeout`, replacing it with
+    /// a freshly opened one. Unset by default: idle connections are kept
+    /// indefinitely.
+    pub fn idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.idle_timeout = Some(idle_timeout);
+        self
+    }
+
+    /// Whether [AdbcConnectionPool::acquire] runs validation (the
+    /// [Self::validator] closure and/or [Self::validation_query]) on every
+    /// check-out. Defaults to `true`; set to `false` to only validate when a
+    /// connection is first opened.
+    pub fn test_on_check_out(mut self, test_on_check_out: bool) -> Self {
+        self.test_on_check_out = test_on_check_out;
+        self
+    }
+
+    /// Run `customizer` against each connection the *first* time it is
+    /// checked out of the pool (e.g. to apply session settings such as
+    /// autocommit or a schema search path via [ConnectionApi::set_option]).
+    pub fn connection_customizer<F>(mut self, customizer: F) -> Self
+    where
+        F: Fn(&AdbcConnection) -> Result<()> + Send + Sync + 'static,
+    {
+        self.customizer = Some(Arc::new(customizer));
+        self
+    }
+
+    /// Run `validator` against a connection every time it is checked out
+    /// (subject to [Self::test_on_check_out]); connections that fail
+    /// validation are discarded and replaced.
+    pub fn validator<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&AdbcConnection) -> Result<()> + Send + Sync + 'static,
+    {
+        self.validator = Some(Arc::new(validator));
+        self
+    }
+
+    /// A simpler alternative to [Self::validator]: run `query` via
+    /// [StatementApi::execute] on check-out (subject to
+    /// [Self::test_on_check_out]), discarding the result and treating any
+    /// error as a failed validation.
+    pub fn validation_query(mut self, query: impl Into<String>) -> Self {
+        self.validation_query = Some(query.into());
+        self
+    }
+
+    pub fn build(self) -> AdbcConnectionPool {
+        AdbcConnectionPool(Arc::new(PoolInner {
+            database: self.database,
+            idle: Mutex::new(VecDeque::new()),
+            available: Condvar::new(),
+            created: AtomicU32::new(0),
+            max_size: self.max_size,
+            acquire_timeout: self.acquire_timeout,
+            idle_timeout: self.idle_timeout,
+            test_on_check_out: self.test_on_check_out,
+            customizer: self.customizer,
+            validator: self.validator,
+            validation_query: self.validation_query,
+        }))
+    }
+}
+
+struct IdleConnection {
+    connection: AdbcConnection,
+    customized: bool,
+    idle_since: Instant,
+}
+
+struct PoolInner {
+    database: AdbcDatabase,
+    idle: Mutex<VecDeque<IdleConnection>>,
+    available: Condvar,
+    created: AtomicU32,
+    max_size: u32,
+    acquire_timeout: Duration,
+    idle_timeout: Option<Duration>,
+    test_on_check_out: bool,
+    customizer: Option<Arc<ConnectionCustomizer>>,
+    validator: Option<Arc<ConnectionCustomizer>>,
+    validation_query: Option<String>,
+}
+
+// Safety: `AdbcConnection` embeds an `Rc`, which is otherwise unsound to
+// touch from more than one thread at a time. The pool never does: every
+// connection lives in exactly one place at a time -- in `idle` (behind its
+// `Mutex`) or exclusively owned by one `PooledConnection` -- and is moved,
+// never cloned, between those states. So at most one thread ever has its
+// hands on a given connection's `Rc`, which is all soundness requires.
+unsafe impl Send for PoolInner {}
+unsafe impl Sync for PoolInner {}
+
+/// A bounded pool of [AdbcConnection]s, modeled on `r2d2::Pool`.
+///
+/// Connections are created lazily (up to `max_size`) and reused across
+/// [AdbcConnectionPool::acquire] calls. Cheaply [Clone]able; clones share
+/// the same pool of connections.
+#[derive(Clone)]
+pub struct AdbcConnectionPool(Arc<PoolInner>);
+
+impl AdbcConnectionPool {
+    /// Start building a pool for `database`.
+    pub fn builder(database: AdbcDatabase) -> AdbcConnectionPoolBuilder {
+        AdbcConnectionPoolBuilder::new(database)
+    }
+
+    /// Check out a connection, blocking up to `acquire_timeout` for one to
+    /// become available (either idle, or by opening a new one if the pool
+    /// has not yet reached `max_size`).
+    pub fn acquire(&self) -> Result<PooledConnection> {
+        let deadline = Instant::now() + self.0.acquire_timeout;
+        let mut idle = self.0.idle.lock().unwrap();
+        loop {
+            if let Some(candidate) = idle.pop_front() {
+                drop(idle);
+                if self.expired(&candidate) {
+                    // Idle too long: discard and try the next one (or open a
+                    // fresh connection) instead of handing out a stale one.
+                    self.0.created.fetch_sub(1, Ordering::SeqCst);
+                    idle = self.0.idle.lock().unwrap();
+                    continue;
+                }
+                match self.checkout(candidate, true) {
+                    Ok(conn) => return Ok(conn),
+                    Err(_) => {
+                        // Failed validation: the connection was discarded: loop
+                        // back around to try another, or open a fresh one.
+                        self.0.created.fetch_sub(1, Ordering::SeqCst);
+                        idle = self.0.idle.lock().unwrap();
+                        continue;
+                    }
+                }
+            }
+
+            if self.0.created.load(Ordering::SeqCst) < self.0.max_size {
+                self.0.created.fetch_add(1, Ordering::SeqCst);
+                drop(idle);
+                let connection = match self.0.database.new_connection().and_then(|b| b.init()) {
+                    Ok(connection) => connection,
+                    Err(err) => {
+                        self.0.created.fetch_sub(1, Ordering::SeqCst);
+                        return Err(err);
+                    }
+                };
+                return self.checkout(
+                    IdleConnection {
+                        connection,
+                        customized: false,
+                        idle_since: Instant::now(),
+                    },
+                    false,
+                );
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(Error::new(
+                    "timed out waiting for an available pooled connection",
+                    AdbcStatusCode::Timeout,
+                ));
+            }
+            let (guard, _timeout) = self
+                .0
+                .available
+                .wait_timeout(idle, deadline - now)
+                .unwrap();
+            idle = guard;
+        }
+    }
+
+    /// Whether `candidate` has sat idle longer than `idle_timeout`.
+    fn expired(&self, candidate: &IdleConnection) -> bool {
+        self.0
+            .idle_timeout
+            .is_some_and(|idle_timeout| candidate.idle_since.elapsed() >= idle_timeout)
+    }
+
+    /// Run the validator/validation query (if any, and if this is a freshly
+    /// opened connection or `test_on_check_out` is set) and the customizer (only
+    /// the first time a connection is checked out), producing a
+    /// [PooledConnection] or an error if validation failed (in which case
+    /// the connection is dropped).
+    fn checkout(&self, mut candidate: IdleConnection, from_idle: bool) -> Result<PooledConnection> {
+        if !from_idle || self.0.test_on_check_out {
+            if let Some(validator) = &self.0.validator {
+                validator(&candidate.connection)?;
+            }
+            if let Some(query) = &self.0.validation_query {
+                let mut statement = candidate.connection.new_statement()?;
+                statement.set_sql_query(query)?;
+                statement.execute()?;
+            }
+        }
+        if !candidate.customized {
+            if let Some(customizer) = &self.0.customizer {
+                customizer(&candidate.connection)?;
+            }
+            candidate.customized = true;
+        }
+        Ok(PooledConnection {
+            pool: self.0.clone(),
+            connection: Some(candidate.connection),
+            customized: candidate.customized,
+        })
+    }
+}
+
+/// A connection checked out of an [AdbcConnectionPool].
+///
+/// On drop, any in-progress transaction is rolled back and the connection
+/// is returned to the pool so half-finished transactions never leak to the
+/// next borrower.
+pub struct PooledConnection {
+    pool: Arc<PoolInner>,
+    connection: Option<AdbcConnection>,
+    customized: bool,
+}
+
+// Safety: see `PoolInner` -- a `PooledConnection` is the exclusive owner of
+// its `AdbcConnection` (it is not `Clone`), so handing one to another
+// thread moves that exclusive ownership along with it rather than sharing
+// the underlying `Rc`. It must stay `!Sync`: `ConnectionApi` methods take
+// `&self` and rely on the caller having exclusive logical access to the
+// FFI connection, so two threads sharing a `&PooledConnection` could race
+// the same connection's vtable calls, violating ADBC's "never accessed
+// concurrently from multiple threads" contract.
+unsafe impl Send for PooledConnection {}
+
+impl std::ops::Deref for PooledConnection {
+    type Target = AdbcConnection;
+
+    fn deref(&self) -> &AdbcConnection {
+        self.connection.as_ref().expect("connection taken")
+    }
+}
+
+impl Drop for PooledConnection {
+    fn drop(&mut self) {
+        if let Some(connection) = self.connection.take() {
+            // Best-effort: if the driver has no open transaction (autocommit
+            // is on), this is a harmless no-op error that we discard.
+            let _ = connection.rollback();
+            self.pool.idle.lock().unwrap().push_back(IdleConnection {
+                connection,
+                customized: self.customized,
+                idle_since: Instant::now(),
+            });
+            self.pool.available.notify_one();
+        }
+    }
+}
+
+/// Async wrappers over [AdbcConnection] and [AdbcStatement] that offload
+/// each blocking FFI call onto a [tokio::task::spawn_blocking] thread, for
+/// use from an async runtime without stalling the executor.
+///
+/// Gated behind the `tokio` feature since it is an optional, heavier
+/// dependency that most embedders of this crate do not need.
+#[cfg(feature = "tokio")]
+pub mod r#async {
+    use std::cell::UnsafeCell;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    use arrow::error::ArrowError;
+    use arrow::record_batch::RecordBatch;
+    use futures::stream::{self, Stream, StreamExt};
+
+    use super::*;
+    use crate::interface::{AsyncConnectionApi, AsyncStatementApi};
+
+    /// Default bound on how many partitions [AsyncConnection::read_partitions]
+    /// reads concurrently when the caller doesn't pick a concurrency level
+    /// themselves: one task per partition, capped so a result set with an
+    /// unusually large partition count doesn't spawn them all at once.
+    const DEFAULT_PARTITION_CONCURRENCY_CEILING: usize = 16;
+
+    /// `FFI_AdbcConnection`/`FFI_AdbcStatement` are documented as safe to use
+    /// from multiple threads as long as access is serialized; wrapping them
+    /// in a [Mutex] (so only one blocking call runs at a time) upholds that
+    /// contract and lets us implement `Send`/`Sync` on the wrapper itself,
+    /// exactly as [crate::ffi::FFI_AdbcConnection]'s own docs suggest.
+    ///
+    /// This also doubles as the lock domain an [AsyncConnection] shares with
+    /// every [AsyncStatement] created from it: [AdbcStatement] embeds a
+    /// clone of its parent [AdbcConnection] (and therefore the same
+    /// `Rc<FFI_AdbcConnection>`), so a statement must never be accessed
+    /// under a *different* lock than its connection -- that would let two
+    /// blocking-pool threads clone/drop the shared `Rc` concurrently, a data
+    /// race on its non-atomic refcount. [AsyncStatement] therefore locks
+    /// this same `SerializedAccess<AdbcConnection>` rather than wrapping its
+    /// own statement in a second, independent one.
+    struct SerializedAccess<T>(Mutex<T>);
+
+    unsafe impl<T> Send for SerializedAccess<T> {}
+    unsafe impl<T> Sync for SerializedAccess<T> {}
+
+    /// An async-friendly wrapper over [AdbcConnection].
+    ///
+    /// Every method runs the equivalent [ConnectionApi] call on a blocking
+    /// thread via [tokio::task::spawn_blocking]; a panic inside the driver
+    /// is re-raised in the calling task rather than silently becoming an
+    /// `Err`.
+    #[derive(Clone)]
+    pub struct AsyncConnection {
+        inner: Arc<SerializedAccess<AdbcConnection>>,
+    }
+
+    impl AsyncConnection {
+        pub fn new(connection: AdbcConnection) -> Self {
+            Self {
+                inner: Arc::new(SerializedAccess(Mutex::new(connection))),
+            }
+        }
+
+        /// Run `f` against the wrapped connection on a blocking thread,
+        /// propagating a driver panic back to the caller.
+        async fn spawn<F, R>(&self, f: F) -> Result<R>
+        where
+            F: FnOnce(&AdbcConnection) -> Result<R> + Send + 'static,
+            R: Send + 'static,
+        {
+            let inner = self.inner.clone();
+            tokio::task::spawn_blocking(move || {
+                let guard = inner.0.lock().expect("connection mutex poisoned");
+                f(&guard)
+            })
+            .await
+            .unwrap_or_else(|join_err| std::panic::resume_unwind(join_err.into_panic()))
+        }
+
+        pub async fn get_info(&self, info_codes: Vec<u32>) -> Result<AsyncRecordBatchStream> {
+            let reader = self
+                .spawn(move |conn| conn.get_info(&info_codes))
+                .await?;
+            Ok(AsyncRecordBatchStream::new(reader))
+        }
+
+        pub async fn get_objects(
+            &self,
+            depth: AdbcObjectDepth,
+            catalog: Option<String>,
+            db_schema: Option<String>,
+            table_name: Option<String>,
+            column_name: Option<String>,
+        ) -> Result<AsyncRecordBatchStream> {
+            let reader = self
+                .spawn(move |conn| {
+                    conn.get_objects(
+                        depth,
+                        catalog.as_deref(),
+                        db_schema.as_deref(),
+                        table_name.as_deref(),
+                        None,
+                        column_name.as_deref(),
+                    )
+                })
+                .await?;
+            Ok(AsyncRecordBatchStream::new(reader))
+        }
+
+        pub async fn get_table_schema(
+            &self,
+            catalog: Option<String>,
+            db_schema: Option<String>,
+            table_name: String,
+        ) -> Result<Schema> {
+            self.spawn(move |conn| {
+                conn.get_table_schema(catalog.as_deref(), db_schema.as_deref(), &table_name)
+            })
+            .await
+        }
+
+        pub async fn get_table_types(&self) -> Result<Vec<String>> {
+            self.spawn(|conn| conn.get_table_types()).await
+        }
+
+        pub async fn commit(&self) -> Result<()> {
+            self.spawn(|conn| conn.commit()).await
+        }
+
+        pub async fn rollback(&self) -> Result<()> {
+            self.spawn(|conn| conn.rollback()).await
+        }
+
+        /// Create a new statement, still bound to this async connection.
+        ///
+        /// The returned [AsyncStatement] shares this connection's lock
+        /// domain (see [SerializedAccess]) rather than getting its own, so
+        /// that dropping/cloning the `Rc<FFI_AdbcConnection>` the statement
+        /// embeds is always serialized with access to this connection.
+        pub async fn new_statement(&self) -> Result<AsyncStatement> {
+            let statement = self.spawn(|conn| conn.new_statement()).await?;
+            Ok(AsyncStatement {
+                connection_lock: self.inner.clone(),
+                statement: Arc::new(UnsafeCell::new(Some(statement))),
+                live_clones: Arc::new(AtomicUsize::new(1)),
+            })
+        }
+
+        /// Open a single partition (as returned by
+        /// [AsyncStatement::execute_partitioned]) as a lazily-pulled async
+        /// stream, rather than eagerly draining it like [Self::read_partitions]
+        /// does. Useful when a caller wants to stream one partition at a
+        /// time itself, e.g. as a single DataFusion partition.
+        pub async fn read_partition(&self, partition: Vec<u8>) -> Result<AsyncRecordBatchStream> {
+            let reader = self.spawn(move |conn| conn.read_partition(&partition)).await?;
+            Ok(AsyncRecordBatchStream::new(reader))
+        }
+
+        /// Open and fully drain each of `partitions` (as returned by
+        /// [AsyncStatement::execute_partitioned]), reading up to
+        /// [DEFAULT_PARTITION_CONCURRENCY_CEILING] partitions at once
+        /// (capped at `partitions.len()`) rather than one at a time, and
+        /// preserving the order of `partitions` in the result.
+        ///
+        /// Use [Self::read_partitions_with_concurrency] to pick a different
+        /// concurrency level or opt out of order preservation.
+        pub async fn read_partitions(&self, partitions: Vec<Vec<u8>>) -> Result<Vec<RecordBatch>> {
+            let concurrency = partitions
+                .len()
+                .min(DEFAULT_PARTITION_CONCURRENCY_CEILING);
+            self.read_partitions_with_concurrency(partitions, concurrency, true)
+                .await
+        }
+
+        /// Like [Self::read_partitions], but with an explicit `concurrency`
+        /// bound on in-flight partition reads. If `preserve_order` is false,
+        /// a partition's batches come back as soon as it finishes, rather
+        /// than in `partitions`' original order.
+        pub async fn read_partitions_with_concurrency(
+            &self,
+            partitions: Vec<Vec<u8>>,
+            concurrency: usize,
+            preserve_order: bool,
+        ) -> Result<Vec<RecordBatch>> {
+            let concurrency = concurrency.max(1);
+            let reads = partitions.into_iter().map(|partition| {
+                let this = self.clone();
+                async move {
+                    this.spawn(move |conn| {
+                        conn.read_partition(&partition)?
+                            .collect::<std::result::Result<Vec<RecordBatch>, ArrowError>>()
+                            .map_err(|err| Error::new(err.to_string(), AdbcStatusCode::IO))
+                    })
+                    .await
+                }
+            });
+            let results: Vec<Result<Vec<RecordBatch>>> = if preserve_order {
+                stream::iter(reads).buffered(concurrency).collect().await
+            } else {
+                stream::iter(reads).buffer_unordered(concurrency).collect().await
+            };
+            let mut batches = Vec::new();
+            for result in results {
+                batches.extend(result?);
+            }
+            Ok(batches)
+        }
+    }
+
+    /// Lets [AsyncConnection] stand in wherever driver-author code is
+    /// written against [AsyncConnectionApi] instead of its own inherent
+    /// `get_objects` method, so that code stays agnostic to whether it is
+    /// talking to a blocking-pool-backed connection like this one or a
+    /// connection backed by a natively async client.
+    #[async_trait::async_trait]
+    impl AsyncConnectionApi for AsyncConnection {
+        type Error = Error;
+
+        async fn get_objects(
+            &self,
+            depth: AdbcObjectDepth,
+            catalog: Option<&str>,
+            db_schema: Option<&str>,
+            table_name: Option<&str>,
+            table_type: Option<&[&str]>,
+            column_name: Option<&str>,
+        ) -> Result<Box<dyn RecordBatchReader>> {
+            let catalog = catalog.map(str::to_string);
+            let db_schema = db_schema.map(str::to_string);
+            let table_name = table_name.map(str::to_string);
+            let table_type: Option<Vec<String>> =
+                table_type.map(|types| types.iter().map(|t| t.to_string()).collect());
+            let column_name = column_name.map(str::to_string);
+            let reader = self
+                .spawn(move |conn| {
+                    let table_type: Option<Vec<&str>> = table_type
+                        .as_ref()
+                        .map(|types| types.iter().map(String::as_str).collect());
+                    conn.get_objects(
+                        depth,
+                        catalog.as_deref(),
+                        db_schema.as_deref(),
+                        table_name.as_deref(),
+                        table_type.as_deref(),
+                        column_name.as_deref(),
+                    )
+                })
+                .await?;
+            Ok(reader)
+        }
+    }
+
+    /// An async-friendly wrapper over [AdbcStatement].
+    ///
+    /// Locks the parent [AsyncConnection]'s [SerializedAccess] rather than
+    /// one of its own -- see that type's doc comment for why a statement
+    /// can't safely get an independent lock domain. That includes teardown:
+    /// see the [Drop] impl below for why dropping the last clone also needs
+    /// to take the lock, and why `live_clones` -- not [Arc::strong_count] --
+    /// is what decides which dropping clone is the last one.
+    pub struct AsyncStatement {
+        connection_lock: Arc<SerializedAccess<AdbcConnection>>,
+        statement: Arc<UnsafeCell<Option<AdbcStatement>>>,
+        /// Tracks how many [AsyncStatement] clones referring to `statement`
+        /// are still alive. [Clone]/[Drop] bump and drop this with a single
+        /// atomic RMW rather than via [Arc::strong_count]: `strong_count`
+        /// still includes the clone currently being dropped (its own `Arc`
+        /// field hasn't been decremented yet when `Drop::drop` runs), so two
+        /// clones dropped concurrently could each observe a count of 2 and
+        /// both conclude they are not the last -- leaving the real teardown
+        /// to happen later, unsynchronized, when the last `Arc<UnsafeCell<_>>`
+        /// is freed outside this lock. `fetch_sub` has no such window: of any
+        /// set of concurrent decrements, exactly one observes the
+        /// pre-decrement value of `1`.
+        live_clones: Arc<AtomicUsize>,
+    }
+
+    impl Clone for AsyncStatement {
+        fn clone(&self) -> Self {
+            self.live_clones.fetch_add(1, Ordering::AcqRel);
+            Self {
+                connection_lock: self.connection_lock.clone(),
+                statement: self.statement.clone(),
+                live_clones: self.live_clones.clone(),
+            }
+        }
+    }
+
+    // Safety: every access to `statement` happens while holding
+    // `connection_lock`, which serializes it against both the parent
+    // connection and any other statement derived from it (see
+    // `SerializedAccess`'s doc comment).
+    unsafe impl Send for AsyncStatement {}
+    unsafe impl Sync for AsyncStatement {}
+
+    impl AsyncStatement {
+        async fn spawn<F, R>(&self, f: F) -> Result<R>
+        where
+            F: FnOnce(&mut AdbcStatement) -> Result<R> + Send + 'static,
+            R: Send + 'static,
+        {
+            let connection_lock = self.connection_lock.clone();
+            let statement = self.statement.clone();
+            tokio::task::spawn_blocking(move || {
+                let _guard = connection_lock.0.lock().expect("connection mutex poisoned");
+                // Safety: `_guard` is the one lock shared by this statement,
+                // its parent connection, and every sibling statement, so
+                // this is the only live access to `statement` right now.
+                let stmt = unsafe { &mut *statement.get() }
+                    .as_mut()
+                    .expect("statement taken");
+                f(stmt)
+            })
+            .await
+            .unwrap_or_else(|join_err| std::panic::resume_unwind(join_err.into_panic()))
+        }
+
+        pub async fn set_sql_query(&self, query: String) -> Result<()> {
+            self.spawn(move |stmt| stmt.set_sql_query(&query)).await
+        }
+
+        pub async fn prepare(&self) -> Result<()> {
+            self.spawn(|stmt| stmt.prepare()).await
+        }
+
+        pub async fn execute(&self) -> Result<AsyncStatementResult> {
+            let result = self.spawn(|stmt| stmt.execute()).await?;
+            Ok(AsyncStatementResult {
+                result: result.result.map(AsyncRecordBatchStream::new),
+                rows_affected: result.rows_affected,
+            })
+        }
+
+        pub async fn execute_update(&self) -> Result<i64> {
+            self.spawn(|stmt| stmt.execute_update()).await
+        }
+
+        /// Execute the statement, returning opaque partition descriptors
+        /// that can be handed to [AsyncConnection::read_partitions] to pull
+        /// the actual result batches, possibly in parallel and/or from other
+        /// nodes in a distributed system.
+        pub async fn execute_partitioned(&self) -> Result<PartitionedStatementResult> {
+            self.spawn(|stmt| stmt.execute_partitioned()).await
+        }
+
+        /// Bind a stream of batches that is itself pulled one batch at a
+        /// time on the blocking pool, so producing the next batch (e.g. from
+        /// another async source collected eagerly beforehand) never blocks
+        /// the executor either.
+        pub async fn bind_stream(&self, stream: Box<dyn RecordBatchReader + Send>) -> Result<()> {
+            self.spawn(move |stmt| stmt.bind_stream(stream)).await
+        }
+    }
+
+    /// Lets [AsyncStatement] stand in wherever driver-author code is written
+    /// against [AsyncStatementApi] instead of its own inherent `execute`/
+    /// `execute_update` methods, so that code stays agnostic to whether it
+    /// is talking to a blocking-pool-backed statement like this one or a
+    /// statement backed by a natively async client.
+    #[async_trait::async_trait]
+    impl AsyncStatementApi for AsyncStatement {
+        type Error = Error;
+
+        async fn execute(&mut self) -> Result<StatementResult> {
+            self.spawn(|stmt| stmt.execute()).await
+        }
+
+        async fn execute_update(&mut self) -> Result<i64> {
+            self.spawn(|stmt| stmt.execute_update()).await
+        }
+    }
+
+    impl Drop for AsyncStatement {
+        /// Dropping the last clone tears down the inner [AdbcStatement],
+        /// which releases a clone of the parent connection's `Rc` -- the
+        /// same non-atomic refcount [Self::spawn] serializes access to via
+        /// `connection_lock`. Without taking that lock here too, a plain
+        /// (non-`spawn_blocking`) drop of the last clone would decrement
+        /// that `Rc` on the caller's thread while another thread is
+        /// concurrently in `spawn`, racing it.
+        ///
+        /// `fetch_sub` returning `1` is what identifies "last clone", not
+        /// [Arc::strong_count] -- see `live_clones`'s doc comment for why.
+        fn drop(&mut self) {
+            if self.live_clones.fetch_sub(1, Ordering::AcqRel) == 1 {
+                let _guard = self
+                    .connection_lock
+                    .0
+                    .lock()
+                    .expect("connection mutex poisoned");
+                // Safety: we are the last reference to `statement` and hold
+                // the lock shared with the parent connection, so nothing
+                // else can be touching it concurrently.
+                unsafe { &mut *self.statement.get() }.take();
+            }
+        }
+    }
+
+    /// The async equivalent of [StatementResult].
+    pub struct AsyncStatementResult {
+        pub result: Option<AsyncRecordBatchStream>,
+        pub rows_affected: i64,
+    }
+
+    /// Wraps a `Box<dyn RecordBatchReader>` so that pulling the next batch
+    /// happens via [tokio::task::spawn_blocking], meaning a slow/blocking
+    /// `next()` call on the underlying driver never stalls the executor.
+    pub struct AsyncRecordBatchStream {
+        // `None` once the underlying reader has been handed off to a
+        // blocking task and not yet returned, or once it is exhausted.
+        reader: Option<SendReader>,
+    }
+
+    /// `Box<dyn RecordBatchReader>` is not `Send` in general (many drivers
+    /// stash non-`Send` FFI state behind it), but since we only ever touch
+    /// it from one blocking task at a time -- handing it off and getting it
+    /// back before the next poll -- moving it across that single thread hop
+    /// is sound.
+    struct SendReader(Box<dyn RecordBatchReader>);
+    unsafe impl Send for SendReader {}
+
+    impl AsyncRecordBatchStream {
+        fn new(reader: Box<dyn RecordBatchReader>) -> Self {
+            Self {
+                reader: Some(SendReader(reader)),
+            }
+        }
+
+        /// Pull the next batch, if any, offloading the call to a blocking thread.
+        pub async fn next_batch(&mut self) -> Option<std::result::Result<RecordBatch, ArrowError>> {
+            let SendReader(mut reader) = self.reader.take()?;
+            let (item, reader) = tokio::task::spawn_blocking(move || {
+                let item = reader.next();
+                (item, reader)
+            })
+            .await
+            .unwrap_or_else(|join_err| std::panic::resume_unwind(join_err.into_panic()));
+            self.reader = Some(SendReader(reader));
+            item
+        }
+    }
+
+    impl AsyncRecordBatchStream {
+        /// View this as a [futures::Stream] of batches.
+        pub fn into_stream(
+            self,
+        ) -> impl Stream<Item = std::result::Result<RecordBatch, ArrowError>> {
+            futures::stream::unfold(self, |mut this| async move {
+                let item = this.next_batch().await;
+                item.map(|item| (item, this))
+            })
+        }
+    }
+}
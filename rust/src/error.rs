@@ -0,0 +1,194 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! ADBC error types, as defined in [adbc.h](https://github.com/apache/arrow-adbc/blob/main/adbc.h).
+use std::ffi::{c_char, CStr, CString};
+use std::ptr::null_mut;
+
+/// Error codes for operations that may fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i32)]
+pub enum AdbcStatusCode {
+    /// No error.
+    Ok = 0,
+    /// An unknown error occurred.
+    UnknownError = 1,
+    /// The operation is not implemented or supported.
+    NotImplemented = 2,
+    /// A requested resource was not found.
+    NotFound = 3,
+    /// A requested resource already exists.
+    AlreadyExists = 4,
+    /// The arguments are invalid, likely a programming error.
+    InvalidArgument = 5,
+    /// The preconditions for the operation are not met, likely a
+    /// programming error.
+    InvalidState = 6,
+    /// Invalid data was processed (not a programming error).
+    InvalidData = 7,
+    /// The database's integrity was affected (e.g. a constraint violation).
+    Integrity = 8,
+    /// An error internal to the driver occurred.
+    Internal = 9,
+    /// An I/O error occurred.
+    IO = 10,
+    /// The operation was cancelled, not due to a timeout.
+    Cancelled = 11,
+    /// The operation was cancelled due to a timeout.
+    Timeout = 12,
+    /// Authentication failed.
+    Unauthenticated = 13,
+    /// The client is not authorized to perform the given operation.
+    Unauthorized = 14,
+}
+
+/// The Rust-native representation of an ADBC error.
+///
+/// Implementors carry a [AdbcStatusCode] plus a human-readable message, as
+/// well as the additional ADBC 1.1.0 error details: a 5-character
+/// `sqlstate`, a driver-defined `vendor_code`, and arbitrary key/value
+/// `details` pairs. All of the 1.1.0 fields default to empty so that
+/// drivers written against the 1.0.0 model keep compiling unchanged.
+pub trait AdbcError {
+    /// A human-readable message describing the error.
+    fn message(&self) -> &str;
+
+    /// The status code associated with the error.
+    fn status_code(&self) -> AdbcStatusCode {
+        AdbcStatusCode::UnknownError
+    }
+
+    /// The SQLSTATE code, if the driver/database provides one.
+    ///
+    /// SQLSTATEs are 5-character codes standardized by ODBC/the SQL spec
+    /// (e.g. the `23` class indicates an integrity constraint violation).
+    fn sqlstate(&self) -> Option<[u8; 5]> {
+        None
+    }
+
+    /// A vendor-specific error code, if the driver provides one.
+    fn vendor_code(&self) -> Option<i32> {
+        None
+    }
+
+    /// Arbitrary driver-supplied metadata about the error, as key/binary-value
+    /// pairs (for example, a server-side query id or a full stack trace).
+    fn details(&self) -> Vec<(String, Vec<u8>)> {
+        Vec::new()
+    }
+}
+
+/// The FFI representation of an ADBC 1.1.0 error detail entry.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct FFI_AdbcErrorDetail {
+    pub key: *const c_char,
+    pub value: *const u8,
+    pub value_length: usize,
+}
+
+/// The FFI representation of an ADBC error, as populated by a driver.
+#[repr(C)]
+#[derive(Debug)]
+pub struct FFI_AdbcError {
+    /// A human-readable error message, or null if there is none.
+    pub message: *mut c_char,
+    /// A vendor-specific error code, or 0 if there is none.
+    pub vendor_code: i32,
+    /// A SQLSTATE error code, if provided, as defined by the SQL:2003
+    /// standard. If not set, it should be set to `"\0\0\0\0\0"`.
+    pub sqlstate: [c_char; 5],
+    /// Release the contained error.
+    pub release: ::std::option::Option<unsafe extern "C" fn(error: *mut FFI_AdbcError)>,
+}
+
+impl FFI_AdbcError {
+    pub fn empty() -> Self {
+        Self {
+            message: null_mut(),
+            vendor_code: 0,
+            sqlstate: [0; 5],
+            release: None,
+        }
+    }
+
+    /// Populate this error from a Rust [AdbcError], allocating a message and
+    /// (if the driver populates it) filling in the SQLSTATE/vendor code.
+    ///
+    /// The 1.1.0 `details()` are not carried by this struct alone; use
+    /// `AdbcErrorFromArrayStream` to retrieve them when reading them back out
+    /// of a result stream.
+    pub fn set(&mut self, error: &impl AdbcError) {
+        self.release_message();
+        let message = CString::new(error.message()).unwrap_or_default();
+        self.message = message.into_raw();
+        self.vendor_code = error.vendor_code().unwrap_or(0);
+        self.sqlstate = error
+            .sqlstate()
+            .map(|bytes| bytes.map(|b| b as c_char))
+            .unwrap_or([0; 5]);
+    }
+
+    /// Read back the SQLSTATE populated by the driver, if any (i.e. if it is
+    /// not the all-zero sentinel).
+    pub fn sqlstate(&self) -> Option<[u8; 5]> {
+        if self.sqlstate == [0; 5] {
+            None
+        } else {
+            Some(self.sqlstate.map(|b| b as u8))
+        }
+    }
+
+    /// Read back the vendor code populated by the driver, if any (i.e. if it
+    /// is not the `0` sentinel).
+    pub fn vendor_code(&self) -> Option<i32> {
+        if self.vendor_code == 0 {
+            None
+        } else {
+            Some(self.vendor_code)
+        }
+    }
+
+    fn release_message(&mut self) {
+        if !self.message.is_null() {
+            unsafe {
+                drop(CString::from_raw(self.message));
+            }
+            self.message = null_mut();
+        }
+    }
+}
+
+impl Drop for FFI_AdbcError {
+    fn drop(&mut self) {
+        if let Some(release) = self.release {
+            unsafe { release(self) };
+        } else {
+            self.release_message();
+        }
+    }
+}
+
+unsafe fn c_char_to_string(s: *const c_char) -> Option<String> {
+    if s.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(s).to_string_lossy().into_owned())
+    }
+}
+
+pub(crate) use c_char_to_string as ffi_message_to_string;
@@ -16,13 +16,51 @@
 // under the License.
 
 //! ADBC FFI structs, as defined in [adbc.h](https://github.com/apache/arrow-adbc/blob/main/adbc.h).
-use std::ffi::{c_char, c_void, CStr};
+use std::ffi::{c_char, c_void};
+use std::marker::PhantomData;
 use std::ptr::{null, null_mut};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Condvar, Mutex};
+use std::time::{Duration, Instant};
 
 use crate::error::{AdbcStatusCode, FFI_AdbcError};
 use arrow::ffi::{FFI_ArrowArray, FFI_ArrowSchema};
 use arrow::ffi_stream::FFI_ArrowArrayStream;
 
+/// The error returned by `close()` on an FFI struct when its release
+/// callback reports failure.
+///
+/// Unlike [FFI_AdbcError], this owns its data rather than carrying a raw C
+/// string, so it can be returned from a safe API and outlive the
+/// underlying FFI call.
+#[derive(Debug, Clone)]
+pub struct CloseError {
+    pub message: String,
+    pub status_code: AdbcStatusCode,
+    pub sqlstate: Option<[u8; 5]>,
+    pub vendor_code: Option<i32>,
+}
+
+impl std::fmt::Display for CloseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}: {}", self.status_code, self.message)
+    }
+}
+
+impl std::error::Error for CloseError {}
+
+impl CloseError {
+    fn from_ffi(status: AdbcStatusCode, error: &FFI_AdbcError) -> Self {
+        Self {
+            message: unsafe { crate::error::ffi_message_to_string(error.message) }
+                .unwrap_or_else(|| "unknown driver error".to_string()),
+            status_code: status,
+            sqlstate: error.sqlstate(),
+            vendor_code: error.vendor_code(),
+        }
+    }
+}
+
 /// An instance of a database.
 ///
 /// Must be kept alive as long as any connections exist.
@@ -44,20 +82,41 @@ impl FFI_AdbcDatabase {
             private_driver: null_mut(),
         }
     }
-}
 
-impl Drop for FFI_AdbcDatabase {
-    fn drop(&mut self) {
-        if let Some(private_driver) = unsafe { self.private_driver.as_ref() } {
-            if let Some(release) = private_driver.database_release {
+    /// Release the database, returning the driver's error (if any) instead
+    /// of only logging it to stderr like [Drop] does, and marking this struct released so
+    /// the subsequent [Drop] is a no-op.
+    pub fn close(mut self) -> Result<(), CloseError> {
+        self.release_checked()
+    }
+
+    fn release_checked(&mut self) -> Result<(), CloseError> {
+        let result = match unsafe { self.private_driver.as_ref() }.and_then(|d| d.database_release)
+        {
+            Some(release) => {
                 let mut error = FFI_AdbcError::empty();
                 let status = unsafe { release(self, &mut error) };
-                if status != AdbcStatusCode::Ok {
-                    panic!("Failed to cleanup database: {}", unsafe {
-                        CStr::from_ptr(error.message).to_string_lossy()
-                    });
+                if status == AdbcStatusCode::Ok {
+                    Ok(())
+                } else {
+                    Err(CloseError::from_ffi(status, &error))
                 }
             }
+            None => Ok(()),
+        };
+        self.private_data = null_mut();
+        self.private_driver = null_mut();
+        result
+    }
+}
+
+impl Drop for FFI_AdbcDatabase {
+    fn drop(&mut self) {
+        // Callers who need to observe (or recover from) a failed release
+        // should call `close()` instead of relying on `Drop`; this is just a
+        // best-effort side-channel so the failure isn't completely silent.
+        if let Err(e) = self.release_checked() {
+            eprintln!("FFI_AdbcDatabase: error releasing database during drop: {e}");
         }
     }
 }
@@ -91,20 +150,107 @@ impl FFI_AdbcConnection {
             private_driver: null_mut(),
         }
     }
-}
 
-impl Drop for FFI_AdbcConnection {
-    fn drop(&mut self) {
-        if let Some(private_driver) = unsafe { self.private_driver.as_ref() } {
-            if let Some(release) = private_driver.connection_release.as_ref() {
+    /// Get a [ConnectionCancelHandle] that can be sent to another thread to
+    /// cancel an in-flight call on this connection.
+    pub fn cancel_handle(&self) -> ConnectionCancelHandle {
+        ConnectionCancelHandle {
+            private_data: self.private_data,
+            private_driver: self.private_driver,
+        }
+    }
+
+    /// Release the connection, returning the driver's error (if any)
+    /// instead of only logging it to stderr like [Drop] does, and marking this struct
+    /// released so the subsequent [Drop] is a no-op. This lets callers
+    /// recover from a failed commit/rollback-on-close, which is common with
+    /// real database connections.
+    pub fn close(mut self) -> Result<(), CloseError> {
+        self.release_checked()
+    }
+
+    fn release_checked(&mut self) -> Result<(), CloseError> {
+        let result = match unsafe { self.private_driver.as_ref() }
+            .and_then(|d| d.connection_release)
+        {
+            Some(release) => {
                 let mut error = FFI_AdbcError::empty();
                 let status = unsafe { release(self, &mut error) };
-                if status != AdbcStatusCode::Ok {
-                    panic!("Failed to cleanup connection: {}", unsafe {
-                        CStr::from_ptr(error.message).to_string_lossy()
-                    });
+                if status == AdbcStatusCode::Ok {
+                    Ok(())
+                } else {
+                    Err(CloseError::from_ffi(status, &error))
                 }
             }
+            None => Ok(()),
+        };
+        self.private_data = null_mut();
+        self.private_driver = null_mut();
+        result
+    }
+}
+
+impl Drop for FFI_AdbcConnection {
+    fn drop(&mut self) {
+        // Callers who need to observe (or recover from) a failed release
+        // should call `close()` instead of relying on `Drop`; this is just a
+        // best-effort side-channel so the failure isn't completely silent.
+        if let Err(e) = self.release_checked() {
+            eprintln!("FFI_AdbcConnection: error releasing connection during drop: {e}");
+        }
+    }
+}
+
+/// A handle that can cancel an in-flight call on the [FFI_AdbcConnection] it
+/// was created from (ADBC 1.1.0's `connection_cancel`), from another thread,
+/// while the original call is blocked across the FFI boundary.
+///
+/// [FFI_AdbcConnection] is intentionally not [Send]/[Sync], so this handle
+/// carries only the raw `private_data`/`private_driver` pointers needed to
+/// invoke the driver's cancel callback, not the connection itself.
+///
+/// # Safety contract
+/// Calling [Self::cancel] is explicitly expected to race with an in-flight
+/// call on the originating connection (the driver is expected to return
+/// [AdbcStatusCode::Cancelled] from the blocked call), but it must not race
+/// with the connection being released: the handle must be dropped no later
+/// than the connection it was created from.
+pub struct ConnectionCancelHandle {
+    private_data: *mut c_void,
+    private_driver: *mut FFI_AdbcDriver,
+}
+
+unsafe impl Send for ConnectionCancelHandle {}
+unsafe impl Sync for ConnectionCancelHandle {}
+
+impl ConnectionCancelHandle {
+    /// Request that the in-flight call on the originating connection be
+    /// cancelled. Returns [AdbcStatusCode::NotImplemented] if the driver
+    /// doesn't support ADBC 1.1.0 cancellation.
+    pub fn cancel(&self) -> Result<(), CloseError> {
+        let Some(cancel) = (unsafe { self.private_driver.as_ref() }).and_then(|d| d.connection_cancel) else {
+            return Err(CloseError {
+                message: "driver does not implement connection_cancel".to_string(),
+                status_code: AdbcStatusCode::NotImplemented,
+                sqlstate: None,
+                vendor_code: None,
+            });
+        };
+        // Reconstruct a transient view over the same `private_data`/
+        // `private_driver` the real connection carries; the driver's
+        // `connection_cancel` only reads those two fields back out. Forget
+        // it afterward so its `Drop` doesn't release the real connection.
+        let mut connection = FFI_AdbcConnection {
+            private_data: self.private_data,
+            private_driver: self.private_driver,
+        };
+        let mut error = FFI_AdbcError::empty();
+        let status = unsafe { cancel(&mut connection, &mut error) };
+        std::mem::forget(connection);
+        if status == AdbcStatusCode::Ok {
+            Ok(())
+        } else {
+            Err(CloseError::from_ffi(status, &error))
         }
     }
 }
@@ -145,20 +291,104 @@ impl FFI_AdbcStatement {
             private_driver: null_mut(),
         }
     }
-}
 
-impl Drop for FFI_AdbcStatement {
-    fn drop(&mut self) {
-        if let Some(private_driver) = unsafe { self.private_driver.as_ref() } {
-            if let Some(release) = private_driver.statement_release {
+    /// Get a [StatementCancelHandle] that can be sent to another thread to
+    /// cancel an in-flight `statement_execute_query` call on this statement.
+    pub fn cancel_handle(&self) -> StatementCancelHandle {
+        StatementCancelHandle {
+            private_data: self.private_data,
+            private_driver: self.private_driver,
+        }
+    }
+
+    /// Release the statement, returning the driver's error (if any) instead
+    /// of only logging it to stderr like [Drop] does, and marking this struct released so
+    /// the subsequent [Drop] is a no-op.
+    pub fn close(mut self) -> Result<(), CloseError> {
+        self.release_checked()
+    }
+
+    fn release_checked(&mut self) -> Result<(), CloseError> {
+        let result = match unsafe { self.private_driver.as_ref() }.and_then(|d| d.statement_release)
+        {
+            Some(release) => {
                 let mut error = FFI_AdbcError::empty();
                 let status = unsafe { release(self, &mut error) };
-                if status != AdbcStatusCode::Ok {
-                    panic!("Failed to cleanup statement: {}", unsafe {
-                        CStr::from_ptr(error.message).to_string_lossy()
-                    });
+                if status == AdbcStatusCode::Ok {
+                    Ok(())
+                } else {
+                    Err(CloseError::from_ffi(status, &error))
                 }
             }
+            None => Ok(()),
+        };
+        self.private_data = null_mut();
+        self.private_driver = null_mut();
+        result
+    }
+}
+
+impl Drop for FFI_AdbcStatement {
+    fn drop(&mut self) {
+        // Callers who need to observe (or recover from) a failed release
+        // should call `close()` instead of relying on `Drop`; this is just a
+        // best-effort side-channel so the failure isn't completely silent.
+        if let Err(e) = self.release_checked() {
+            eprintln!("FFI_AdbcStatement: error releasing statement during drop: {e}");
+        }
+    }
+}
+
+/// A handle that can cancel an in-flight `statement_execute_query` call on
+/// the [FFI_AdbcStatement] it was created from (ADBC 1.1.0's
+/// `statement_cancel`), from another thread, while the original call is
+/// blocked across the FFI boundary.
+///
+/// [FFI_AdbcStatement] is intentionally not [Send]/[Sync], so this handle
+/// carries only the raw `private_data`/`private_driver` pointers needed to
+/// invoke the driver's cancel callback, not the statement itself.
+///
+/// # Safety contract
+/// Calling [Self::cancel] is explicitly expected to race with an in-flight
+/// `execute` call on the originating statement (the driver is expected to
+/// return [AdbcStatusCode::Cancelled] from the blocked call), but it must
+/// not race with the statement being released: the handle must be dropped
+/// no later than the statement it was created from.
+pub struct StatementCancelHandle {
+    private_data: *mut c_void,
+    private_driver: *mut FFI_AdbcDriver,
+}
+
+unsafe impl Send for StatementCancelHandle {}
+unsafe impl Sync for StatementCancelHandle {}
+
+impl StatementCancelHandle {
+    /// Request that the in-flight call on the originating statement be
+    /// cancelled. Returns [AdbcStatusCode::NotImplemented] if the driver
+    /// doesn't support ADBC 1.1.0 cancellation.
+    pub fn cancel(&self) -> Result<(), CloseError> {
+        let Some(cancel) = (unsafe { self.private_driver.as_ref() }).and_then(|d| d.statement_cancel) else {
+            return Err(CloseError {
+                message: "driver does not implement statement_cancel".to_string(),
+                status_code: AdbcStatusCode::NotImplemented,
+                sqlstate: None,
+                vendor_code: None,
+            });
+        };
+        // See [ConnectionCancelHandle::cancel]: reconstruct a transient view
+        // over the real statement's fields and forget it so its `Drop`
+        // doesn't release the real statement.
+        let mut statement = FFI_AdbcStatement {
+            private_data: self.private_data,
+            private_driver: self.private_driver,
+        };
+        let mut error = FFI_AdbcError::empty();
+        let status = unsafe { cancel(&mut statement, &mut error) };
+        std::mem::forget(statement);
+        if status == AdbcStatusCode::Ok {
+            Ok(())
+        } else {
+            Err(CloseError::from_ffi(status, &error))
         }
     }
 }
@@ -195,6 +425,22 @@ impl FFI_AdbcPartitions {
             release: None,
         }
     }
+
+    /// Copy the partition descriptors out into owned buffers, leaving this
+    /// struct untouched (the caller is still responsible for calling
+    /// `release` on it afterwards).
+    pub fn to_vec(&self) -> Vec<Vec<u8>> {
+        if self.partitions.is_null() || self.partition_lengths.is_null() {
+            return Vec::new();
+        }
+        (0..self.num_partitions)
+            .map(|i| unsafe {
+                let ptr = *self.partitions.add(i);
+                let len = *self.partition_lengths.add(i);
+                std::slice::from_raw_parts(ptr, len).to_vec()
+            })
+            .collect()
+    }
 }
 
 impl From<Vec<Vec<u8>>> for FFI_AdbcPartitions {
@@ -480,6 +726,116 @@ pub struct FFI_AdbcDriver {
             arg4: *mut FFI_AdbcError,
         ) -> AdbcStatusCode,
     >,
+
+    // --- ADBC 1.1.0 additions below this line. ---
+    // These are appended after the 1.0.0 fields (rather than interleaved in
+    // alphabetical order like the rest of the struct) so that a struct
+    // populated for [crate::ADBC_VERSION_1_0_0] has the same layout a 1.0.0
+    // caller expects: the tail is simply left zeroed/`None`.
+    pub database_get_option: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: *mut FFI_AdbcDatabase,
+            arg2: *const c_char,
+            arg3: *mut c_char,
+            arg4: *mut usize,
+            arg5: *mut FFI_AdbcError,
+        ) -> AdbcStatusCode,
+    >,
+    pub database_get_option_bytes: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: *mut FFI_AdbcDatabase,
+            arg2: *const c_char,
+            arg3: *mut u8,
+            arg4: *mut usize,
+            arg5: *mut FFI_AdbcError,
+        ) -> AdbcStatusCode,
+    >,
+    pub database_get_option_int: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: *mut FFI_AdbcDatabase,
+            arg2: *const c_char,
+            arg3: *mut i64,
+            arg4: *mut FFI_AdbcError,
+        ) -> AdbcStatusCode,
+    >,
+    pub database_get_option_double: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: *mut FFI_AdbcDatabase,
+            arg2: *const c_char,
+            arg3: *mut f64,
+            arg4: *mut FFI_AdbcError,
+        ) -> AdbcStatusCode,
+    >,
+    pub connection_get_option: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: *mut FFI_AdbcConnection,
+            arg2: *const c_char,
+            arg3: *mut c_char,
+            arg4: *mut usize,
+            arg5: *mut FFI_AdbcError,
+        ) -> AdbcStatusCode,
+    >,
+    pub connection_get_option_bytes: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: *mut FFI_AdbcConnection,
+            arg2: *const c_char,
+            arg3: *mut u8,
+            arg4: *mut usize,
+            arg5: *mut FFI_AdbcError,
+        ) -> AdbcStatusCode,
+    >,
+    pub connection_get_option_int: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: *mut FFI_AdbcConnection,
+            arg2: *const c_char,
+            arg3: *mut i64,
+            arg4: *mut FFI_AdbcError,
+        ) -> AdbcStatusCode,
+    >,
+    pub connection_get_option_double: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: *mut FFI_AdbcConnection,
+            arg2: *const c_char,
+            arg3: *mut f64,
+            arg4: *mut FFI_AdbcError,
+        ) -> AdbcStatusCode,
+    >,
+    pub connection_cancel: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: *mut FFI_AdbcConnection,
+            arg2: *mut FFI_AdbcError,
+        ) -> AdbcStatusCode,
+    >,
+    pub statement_cancel: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: *mut FFI_AdbcStatement,
+            arg2: *mut FFI_AdbcError,
+        ) -> AdbcStatusCode,
+    >,
+    /// Returns just the schema of the result of executing a statement,
+    /// without actually executing it.
+    pub statement_execute_schema: ::std::option::Option<
+        unsafe extern "C" fn(
+            arg1: *mut FFI_AdbcStatement,
+            arg2: *mut FFI_ArrowSchema,
+            arg3: *mut FFI_AdbcError,
+        ) -> AdbcStatusCode,
+    >,
+    /// Get the `index`-th detail attached to `error`, if any.
+    pub error_get_detail: ::std::option::Option<
+        unsafe extern "C" fn(
+            error: *const FFI_AdbcError,
+            index: i32,
+        ) -> crate::error::FFI_AdbcErrorDetail,
+    >,
+    /// If `stream`'s `get_next`/`get_last_error` reported an error, reconstruct
+    /// the full [FFI_AdbcError] (including 1.1.0 details) that produced it.
+    pub error_from_array_stream: ::std::option::Option<
+        unsafe extern "C" fn(
+            stream: *mut FFI_ArrowArrayStream,
+            status: *mut AdbcStatusCode,
+        ) -> *const FFI_AdbcError,
+    >,
 }
 
 macro_rules! empty_driver {
@@ -496,11 +852,18 @@ macro_rules! empty_driver {
 }
 
 impl FFI_AdbcDriver {
-    /// Get an empty [Self], but with all functions filled in with stubs.
+    /// Get an empty driver vtable for the given `version` (one of
+    /// [crate::ADBC_VERSION_1_0_0] or [crate::ADBC_VERSION_1_1_0]), with every
+    /// function valid for that version filled in with a stub.
+    ///
+    /// Fields introduced in a later revision than `version` are left `None`,
+    /// so the struct has exactly the layout a caller requesting an older
+    /// version is entitled to assume: it must never read past the tail it
+    /// asked for.
     ///
     /// Any of the stub functions will simply return [AdbcStatusCode::NotImplemented].
-    pub fn empty() -> Self {
-        empty_driver!(
+    pub fn empty(version: i32) -> Self {
+        let mut driver = empty_driver!(
             database_init,
             database_new,
             database_set_option,
@@ -527,20 +890,64 @@ impl FFI_AdbcDriver {
             statement_set_option,
             statement_set_sql_query,
             statement_set_substrait_plan
-        )
+        );
+        if version >= crate::ADBC_VERSION_1_1_0 {
+            driver.database_get_option = Some(driver_function_stubs::database_get_option);
+            driver.database_get_option_bytes =
+                Some(driver_function_stubs::database_get_option_bytes);
+            driver.database_get_option_int = Some(driver_function_stubs::database_get_option_int);
+            driver.database_get_option_double =
+                Some(driver_function_stubs::database_get_option_double);
+            driver.connection_get_option = Some(driver_function_stubs::connection_get_option);
+            driver.connection_get_option_bytes =
+                Some(driver_function_stubs::connection_get_option_bytes);
+            driver.connection_get_option_int =
+                Some(driver_function_stubs::connection_get_option_int);
+            driver.connection_get_option_double =
+                Some(driver_function_stubs::connection_get_option_double);
+            driver.connection_cancel = Some(driver_function_stubs::connection_cancel);
+            driver.statement_cancel = Some(driver_function_stubs::statement_cancel);
+            driver.statement_execute_schema =
+                Some(driver_function_stubs::statement_execute_schema);
+            driver.error_get_detail = Some(driver_function_stubs::error_get_detail);
+            driver.error_from_array_stream =
+                Some(driver_function_stubs::error_from_array_stream);
+        }
+        driver
+    }
+}
+
+impl FFI_AdbcDriver {
+    /// Release the driver, returning its error (if any) instead of
+    /// only logging it to stderr like [Drop] does, and marking this struct released so the
+    /// subsequent [Drop] is a no-op.
+    pub fn close(mut self) -> Result<(), CloseError> {
+        self.release_checked()
+    }
+
+    fn release_checked(&mut self) -> Result<(), CloseError> {
+        match self.release.take() {
+            Some(release) => {
+                let mut error = FFI_AdbcError::empty();
+                let status = unsafe { release(self, &mut error) };
+                if status == AdbcStatusCode::Ok {
+                    Ok(())
+                } else {
+                    Err(CloseError::from_ffi(status, &error))
+                }
+            }
+            None => Ok(()),
+        }
     }
 }
 
 impl Drop for FFI_AdbcDriver {
     fn drop(&mut self) {
-        if let Some(release) = self.release {
-            let mut error = FFI_AdbcError::empty();
-            let status = unsafe { release(self, &mut error) };
-            if status != AdbcStatusCode::Ok {
-                panic!("Failed to cleanup driver: {}", unsafe {
-                    CStr::from_ptr(error.message).to_string_lossy()
-                });
-            }
+        // Callers who need to observe (or recover from) a failed release
+        // should call `close()` instead of relying on `Drop`; this is just a
+        // best-effort side-channel so the failure isn't completely silent.
+        if let Err(e) = self.release_checked() {
+            eprintln!("FFI_AdbcDriver: error releasing driver during drop: {e}");
         }
     }
 }
@@ -769,10 +1176,312 @@ pub(crate) mod driver_function_stubs {
     ) -> AdbcStatusCode {
         AdbcStatusCode::NotImplemented
     }
+
+    pub(crate) unsafe extern "C" fn database_get_option(
+        _arg1: *mut FFI_AdbcDatabase,
+        _arg2: *const c_char,
+        _arg3: *mut c_char,
+        _arg4: *mut usize,
+        _arg5: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        AdbcStatusCode::NotImplemented
+    }
+
+    pub(crate) unsafe extern "C" fn database_get_option_bytes(
+        _arg1: *mut FFI_AdbcDatabase,
+        _arg2: *const c_char,
+        _arg3: *mut u8,
+        _arg4: *mut usize,
+        _arg5: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        AdbcStatusCode::NotImplemented
+    }
+
+    pub(crate) unsafe extern "C" fn database_get_option_int(
+        _arg1: *mut FFI_AdbcDatabase,
+        _arg2: *const c_char,
+        _arg3: *mut i64,
+        _arg4: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        AdbcStatusCode::NotImplemented
+    }
+
+    pub(crate) unsafe extern "C" fn database_get_option_double(
+        _arg1: *mut FFI_AdbcDatabase,
+        _arg2: *const c_char,
+        _arg3: *mut f64,
+        _arg4: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        AdbcStatusCode::NotImplemented
+    }
+
+    pub(crate) unsafe extern "C" fn connection_get_option(
+        _arg1: *mut FFI_AdbcConnection,
+        _arg2: *const c_char,
+        _arg3: *mut c_char,
+        _arg4: *mut usize,
+        _arg5: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        AdbcStatusCode::NotImplemented
+    }
+
+    pub(crate) unsafe extern "C" fn connection_get_option_bytes(
+        _arg1: *mut FFI_AdbcConnection,
+        _arg2: *const c_char,
+        _arg3: *mut u8,
+        _arg4: *mut usize,
+        _arg5: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        AdbcStatusCode::NotImplemented
+    }
+
+    pub(crate) unsafe extern "C" fn connection_get_option_int(
+        _arg1: *mut FFI_AdbcConnection,
+        _arg2: *const c_char,
+        _arg3: *mut i64,
+        _arg4: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        AdbcStatusCode::NotImplemented
+    }
+
+    pub(crate) unsafe extern "C" fn connection_get_option_double(
+        _arg1: *mut FFI_AdbcConnection,
+        _arg2: *const c_char,
+        _arg3: *mut f64,
+        _arg4: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        AdbcStatusCode::NotImplemented
+    }
+
+    pub(crate) unsafe extern "C" fn connection_cancel(
+        _arg1: *mut FFI_AdbcConnection,
+        _arg2: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        AdbcStatusCode::NotImplemented
+    }
+
+    pub(crate) unsafe extern "C" fn statement_cancel(
+        _arg1: *mut FFI_AdbcStatement,
+        _arg2: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        AdbcStatusCode::NotImplemented
+    }
+
+    pub(crate) unsafe extern "C" fn statement_execute_schema(
+        _arg1: *mut FFI_AdbcStatement,
+        _arg2: *mut FFI_ArrowSchema,
+        _arg3: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        AdbcStatusCode::NotImplemented
+    }
+
+    pub(crate) unsafe extern "C" fn error_get_detail(
+        _error: *const FFI_AdbcError,
+        _index: i32,
+    ) -> crate::error::FFI_AdbcErrorDetail {
+        crate::error::FFI_AdbcErrorDetail {
+            key: null(),
+            value: null(),
+            value_length: 0,
+        }
+    }
+
+    pub(crate) unsafe extern "C" fn error_from_array_stream(
+        _stream: *mut FFI_ArrowArrayStream,
+        status: *mut AdbcStatusCode,
+    ) -> *const FFI_AdbcError {
+        if let Some(status) = status.as_mut() {
+            *status = AdbcStatusCode::NotImplemented;
+        }
+        null()
+    }
+}
+
+/// A bounded pool of raw [FFI_AdbcConnection]s opened against a single
+/// [FFI_AdbcDatabase], for applications that manage the FFI layer directly
+/// rather than through [crate::driver_manager].
+///
+/// This is the FFI-level counterpart to
+/// [crate::driver_manager::AdbcConnectionPool]: it opens connections via
+/// the driver's raw `connection_new`/`connection_init` entrypoints instead
+/// of the safe [crate::driver_manager::AdbcConnection] wrapper. Connections
+/// are created lazily up to `max_size`, and [Self::acquire] blocks up to an
+/// acquire timeout for one to become available.
+///
+/// The pool itself is [Send] + [Sync] (connection creation is serialized
+/// through an internal lock), but the [PooledConnectionGuard] it hands out
+/// is deliberately neither, pinning the checked-out connection to the
+/// acquiring thread for its lifetime, as [FFI_AdbcConnection] requires.
+pub struct FfiConnectionPool {
+    database: Mutex<FFI_AdbcDatabase>,
+    max_size: u32,
+    acquire_timeout: Duration,
+    validate: Option<Box<dyn Fn(&mut FFI_AdbcConnection) -> bool + Send + Sync>>,
+    idle: Mutex<Vec<FFI_AdbcConnection>>,
+    available: Condvar,
+    created: AtomicU32,
+}
+
+unsafe impl Send for FfiConnectionPool {}
+unsafe impl Sync for FfiConnectionPool {}
+
+impl FfiConnectionPool {
+    /// Build a pool of up to `max_size` connections to `database`, whose
+    /// [Self::acquire] calls wait up to `acquire_timeout` for one to become
+    /// available.
+    pub fn new(database: FFI_AdbcDatabase, max_size: u32, acquire_timeout: Duration) -> Self {
+        Self {
+            database: Mutex::new(database),
+            max_size,
+            acquire_timeout,
+            validate: None,
+            idle: Mutex::new(Vec::new()),
+            available: Condvar::new(),
+            created: AtomicU32::new(0),
+        }
+    }
+
+    /// Run `validate` (e.g. a validation query, or `connection_get_info`)
+    /// against every connection popped off the idle list before handing it
+    /// out; connections for which it returns `false` are closed and
+    /// replaced instead.
+    pub fn with_validator<F>(mut self, validate: F) -> Self
+    where
+        F: Fn(&mut FFI_AdbcConnection) -> bool + Send + Sync + 'static,
+    {
+        self.validate = Some(Box::new(validate));
+        self
+    }
+
+    /// Check out a connection, blocking up to the configured acquire
+    /// timeout for one to become available, either idle (and passing
+    /// validation) or newly opened if the pool has not yet reached
+    /// `max_size`.
+    pub fn acquire(&self) -> Result<PooledConnectionGuard<'_>, CloseError> {
+        let deadline = Instant::now() + self.acquire_timeout;
+        let mut idle = self.idle.lock().unwrap();
+        loop {
+            while let Some(mut connection) = idle.pop() {
+                let valid = match &self.validate {
+                    Some(validate) => validate(&mut connection),
+                    None => true,
+                };
+                if valid {
+                    return Ok(self.guard(connection));
+                }
+                let _ = connection.close();
+                self.created.fetch_sub(1, Ordering::SeqCst);
+            }
+
+            if self.created.load(Ordering::SeqCst) < self.max_size {
+                self.created.fetch_add(1, Ordering::SeqCst);
+                drop(idle);
+                return match self.open_connection() {
+                    Ok(connection) => Ok(self.guard(connection)),
+                    Err(e) => {
+                        self.created.fetch_sub(1, Ordering::SeqCst);
+                        Err(e)
+                    }
+                };
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(CloseError {
+                    message: "timed out waiting for an available pooled connection".to_string(),
+                    status_code: AdbcStatusCode::Timeout,
+                    sqlstate: None,
+                    vendor_code: None,
+                });
+            }
+            let (guard, _timeout) = self.available.wait_timeout(idle, deadline - now).unwrap();
+            idle = guard;
+        }
+    }
+
+    fn guard(&self, connection: FFI_AdbcConnection) -> PooledConnectionGuard<'_> {
+        PooledConnectionGuard {
+            pool: self,
+            connection: Some(connection),
+            _not_send: PhantomData,
+        }
+    }
+
+    fn open_connection(&self) -> Result<FFI_AdbcConnection, CloseError> {
+        let mut database = self.database.lock().unwrap();
+        let driver = database.private_driver;
+        let mut connection = FFI_AdbcConnection::empty();
+        connection.private_driver = driver as *mut FFI_AdbcDriver;
+
+        let new_fn = unsafe { driver.as_ref() }.and_then(|d| d.connection_new);
+        let Some(new_fn) = new_fn else {
+            return Err(CloseError {
+                message: "driver does not implement connection_new".to_string(),
+                status_code: AdbcStatusCode::NotImplemented,
+                sqlstate: None,
+                vendor_code: None,
+            });
+        };
+        let mut error = FFI_AdbcError::empty();
+        let status = unsafe { new_fn(&mut connection, &mut error) };
+        if status != AdbcStatusCode::Ok {
+            return Err(CloseError::from_ffi(status, &error));
+        }
+
+        let init_fn = unsafe { driver.as_ref() }.and_then(|d| d.connection_init);
+        let Some(init_fn) = init_fn else {
+            return Err(CloseError {
+                message: "driver does not implement connection_init".to_string(),
+                status_code: AdbcStatusCode::NotImplemented,
+                sqlstate: None,
+                vendor_code: None,
+            });
+        };
+        let mut error = FFI_AdbcError::empty();
+        let status = unsafe { init_fn(&mut connection, &mut *database, &mut error) };
+        if status != AdbcStatusCode::Ok {
+            return Err(CloseError::from_ffi(status, &error));
+        }
+        Ok(connection)
+    }
+}
+
+/// A connection checked out of an [FfiConnectionPool].
+///
+/// Carries a `*mut ()`-shaped marker that makes this type neither [Send]
+/// nor [Sync], pinning it to the thread that acquired it for its lifetime.
+/// On drop, the connection is returned to the pool's idle list.
+pub struct PooledConnectionGuard<'a> {
+    pool: &'a FfiConnectionPool,
+    connection: Option<FFI_AdbcConnection>,
+    _not_send: PhantomData<*mut ()>,
+}
+
+impl std::ops::Deref for PooledConnectionGuard<'_> {
+    type Target = FFI_AdbcConnection;
+
+    fn deref(&self) -> &FFI_AdbcConnection {
+        self.connection.as_ref().expect("connection taken")
+    }
+}
+
+impl std::ops::DerefMut for PooledConnectionGuard<'_> {
+    fn deref_mut(&mut self) -> &mut FFI_AdbcConnection {
+        self.connection.as_mut().expect("connection taken")
+    }
+}
+
+impl Drop for PooledConnectionGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(connection) = self.connection.take() {
+            self.pool.idle.lock().unwrap().push(connection);
+            self.pool.available.notify_one();
+        }
+    }
 }
 
 /// Depth parameter for GetObjects method.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(i32)]
 pub enum AdbcObjectDepth {
     /// Metadata on catalogs, schemas, tables, and columns.
@@ -822,4 +1531,221 @@ mod tests {
             assert!(partitions.private_data.is_null());
         }
     }
+
+    #[test]
+    fn test_adbc_partitions_to_vec() {
+        let case = vec![vec![0, 1, 2, 3], vec![], vec![4, 5, 6]];
+        let mut partitions: FFI_AdbcPartitions = case.clone().into();
+
+        assert_eq!(partitions.to_vec(), case);
+
+        let release_func = partitions.release.unwrap();
+        unsafe {
+            release_func(&mut partitions);
+        }
+        assert_eq!(partitions.to_vec(), Vec::<Vec<u8>>::new());
+    }
+
+    #[test]
+    fn test_empty_driver_1_0_0_leaves_1_1_0_fields_unset() {
+        let driver = FFI_AdbcDriver::empty(crate::ADBC_VERSION_1_0_0);
+
+        assert!(driver.database_init.is_some());
+        assert!(driver.statement_set_substrait_plan.is_some());
+
+        assert!(driver.database_get_option.is_none());
+        assert!(driver.database_get_option_bytes.is_none());
+        assert!(driver.database_get_option_int.is_none());
+        assert!(driver.database_get_option_double.is_none());
+        assert!(driver.connection_get_option.is_none());
+        assert!(driver.connection_get_option_bytes.is_none());
+        assert!(driver.connection_get_option_int.is_none());
+        assert!(driver.connection_get_option_double.is_none());
+        assert!(driver.connection_cancel.is_none());
+        assert!(driver.statement_cancel.is_none());
+        assert!(driver.statement_execute_schema.is_none());
+        assert!(driver.error_get_detail.is_none());
+        assert!(driver.error_from_array_stream.is_none());
+    }
+
+    #[test]
+    fn test_empty_driver_1_1_0_fills_in_new_fields() {
+        let driver = FFI_AdbcDriver::empty(crate::ADBC_VERSION_1_1_0);
+
+        assert!(driver.database_get_option.is_some());
+        assert!(driver.database_get_option_bytes.is_some());
+        assert!(driver.database_get_option_int.is_some());
+        assert!(driver.database_get_option_double.is_some());
+        assert!(driver.connection_get_option.is_some());
+        assert!(driver.connection_get_option_bytes.is_some());
+        assert!(driver.connection_get_option_int.is_some());
+        assert!(driver.connection_get_option_double.is_some());
+        assert!(driver.connection_cancel.is_some());
+        assert!(driver.statement_cancel.is_some());
+        assert!(driver.statement_execute_schema.is_some());
+        assert!(driver.error_get_detail.is_some());
+        assert!(driver.error_from_array_stream.is_some());
+    }
+
+    static CONNECTION_CANCEL_CALLS: AtomicU32 = AtomicU32::new(0);
+    static STATEMENT_CANCEL_CALLS: AtomicU32 = AtomicU32::new(0);
+
+    unsafe extern "C" fn connection_cancel_ok(
+        _connection: *mut FFI_AdbcConnection,
+        _error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        CONNECTION_CANCEL_CALLS.fetch_add(1, Ordering::SeqCst);
+        AdbcStatusCode::Ok
+    }
+
+    unsafe extern "C" fn statement_cancel_ok(
+        _statement: *mut FFI_AdbcStatement,
+        _error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        STATEMENT_CANCEL_CALLS.fetch_add(1, Ordering::SeqCst);
+        AdbcStatusCode::Ok
+    }
+
+    #[test]
+    fn test_connection_cancel_handle_invokes_driver() {
+        let mut driver = FFI_AdbcDriver::empty(crate::ADBC_VERSION_1_1_0);
+        driver.connection_cancel = Some(connection_cancel_ok);
+        let driver = Box::leak(Box::new(driver)) as *mut FFI_AdbcDriver;
+
+        let mut connection = FFI_AdbcConnection::empty();
+        connection.private_driver = driver;
+        let handle = connection.cancel_handle();
+
+        let before = CONNECTION_CANCEL_CALLS.load(Ordering::SeqCst);
+        handle.cancel().unwrap();
+        assert_eq!(CONNECTION_CANCEL_CALLS.load(Ordering::SeqCst), before + 1);
+
+        // Forget the connection: its driver is a leaked stub with no real
+        // `connection_release`, so letting `Drop` run would be meaningless.
+        std::mem::forget(connection);
+    }
+
+    #[test]
+    fn test_connection_cancel_handle_not_implemented() {
+        let mut connection = FFI_AdbcConnection::empty();
+        let driver = FFI_AdbcDriver::empty(crate::ADBC_VERSION_1_0_0);
+        let driver = Box::leak(Box::new(driver)) as *mut FFI_AdbcDriver;
+        connection.private_driver = driver;
+
+        let handle = connection.cancel_handle();
+        let err = handle.cancel().unwrap_err();
+        assert_eq!(err.status_code, AdbcStatusCode::NotImplemented);
+
+        std::mem::forget(connection);
+    }
+
+    #[test]
+    fn test_statement_cancel_handle_invokes_driver() {
+        let mut driver = FFI_AdbcDriver::empty(crate::ADBC_VERSION_1_1_0);
+        driver.statement_cancel = Some(statement_cancel_ok);
+        let driver = Box::leak(Box::new(driver)) as *mut FFI_AdbcDriver;
+
+        let mut statement = FFI_AdbcStatement::empty();
+        statement.private_driver = driver;
+        let handle = statement.cancel_handle();
+
+        let before = STATEMENT_CANCEL_CALLS.load(Ordering::SeqCst);
+        handle.cancel().unwrap();
+        assert_eq!(STATEMENT_CANCEL_CALLS.load(Ordering::SeqCst), before + 1);
+
+        std::mem::forget(statement);
+    }
+
+    static POOL_CONNECTIONS_OPENED: AtomicU32 = AtomicU32::new(0);
+    static POOL_CONNECTIONS_RELEASED: AtomicU32 = AtomicU32::new(0);
+
+    unsafe extern "C" fn pool_connection_new(
+        _connection: *mut FFI_AdbcConnection,
+        _error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        POOL_CONNECTIONS_OPENED.fetch_add(1, Ordering::SeqCst);
+        AdbcStatusCode::Ok
+    }
+
+    unsafe extern "C" fn pool_connection_init(
+        _connection: *mut FFI_AdbcConnection,
+        _database: *mut FFI_AdbcDatabase,
+        _error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        AdbcStatusCode::Ok
+    }
+
+    unsafe extern "C" fn pool_connection_release(
+        _connection: *mut FFI_AdbcConnection,
+        _error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        POOL_CONNECTIONS_RELEASED.fetch_add(1, Ordering::SeqCst);
+        AdbcStatusCode::Ok
+    }
+
+    fn pool_stub_database() -> FFI_AdbcDatabase {
+        let mut driver = FFI_AdbcDriver::empty(crate::ADBC_VERSION_1_0_0);
+        driver.connection_new = Some(pool_connection_new);
+        driver.connection_init = Some(pool_connection_init);
+        driver.connection_release = Some(pool_connection_release);
+        let driver = Box::leak(Box::new(driver)) as *mut FFI_AdbcDriver;
+
+        let mut database = FFI_AdbcDatabase::empty();
+        database.private_driver = driver;
+        database
+    }
+
+    #[test]
+    fn test_ffi_connection_pool_reuses_idle_connections() {
+        let opened_before = POOL_CONNECTIONS_OPENED.load(Ordering::SeqCst);
+        let pool = FfiConnectionPool::new(pool_stub_database(), 2, Duration::from_secs(1));
+
+        {
+            let _guard = pool.acquire().unwrap();
+            assert_eq!(
+                POOL_CONNECTIONS_OPENED.load(Ordering::SeqCst),
+                opened_before + 1
+            );
+        }
+        // The guard was dropped, returning the connection to the idle list:
+        // acquiring again should reuse it rather than opening a new one.
+        let _guard = pool.acquire().unwrap();
+        assert_eq!(
+            POOL_CONNECTIONS_OPENED.load(Ordering::SeqCst),
+            opened_before + 1
+        );
+    }
+
+    #[test]
+    fn test_ffi_connection_pool_validator_discards_invalid_connections() {
+        let opened_before = POOL_CONNECTIONS_OPENED.load(Ordering::SeqCst);
+        let released_before = POOL_CONNECTIONS_RELEASED.load(Ordering::SeqCst);
+        let pool = FfiConnectionPool::new(pool_stub_database(), 2, Duration::from_secs(1))
+            .with_validator(|_| false);
+
+        {
+            let _guard = pool.acquire().unwrap();
+        }
+        let _guard = pool.acquire().unwrap();
+
+        // Every check-out after the first sees a (freshly reopened)
+        // connection fail validation and gets replaced with a new one.
+        assert_eq!(
+            POOL_CONNECTIONS_OPENED.load(Ordering::SeqCst),
+            opened_before + 2
+        );
+        assert_eq!(
+            POOL_CONNECTIONS_RELEASED.load(Ordering::SeqCst),
+            released_before + 1
+        );
+    }
+
+    #[test]
+    fn test_ffi_connection_pool_times_out_at_max_size() {
+        let pool = FfiConnectionPool::new(pool_stub_database(), 1, Duration::from_millis(50));
+
+        let _first = pool.acquire().unwrap();
+        let err = pool.acquire().unwrap_err();
+        assert_eq!(err.status_code, AdbcStatusCode::Timeout);
+    }
 }
@@ -0,0 +1,720 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Exposes any ADBC connection as an Arrow Flight SQL server, so Flight SQL
+//! clients can query it without driver-specific server code.
+//!
+//! Flight SQL's metadata commands are mapped onto existing [ConnectionApi]/
+//! [StatementApi] calls:
+//!
+//! * `CommandGetCatalogs`/`CommandGetDbSchemas`/`CommandGetTables` call
+//!   [ConnectionApi::get_objects] at the matching [AdbcObjectDepth] and
+//!   flatten the nested catalog/db_schema/table result into Flight SQL's
+//!   flat result schemas (see [flatten_catalogs]/[flatten_db_schemas]/
+//!   [flatten_tables]).
+//! * `CommandGetTableTypes` calls [AsyncConnection::get_table_types].
+//! * `CommandStatementQuery`/`CommandPreparedStatementQuery` call
+//!   [StatementApi::set_sql_query] + [StatementApi::prepare] +
+//!   [StatementApi::execute] on a fresh statement.
+//!
+//! This is built against [crate::driver_manager::r#async::AsyncConnection],
+//! not the bare [ConnectionApi] trait, since a Flight SQL service must be
+//! `Send`/`'static` across requests and the raw FFI connection is not.
+//!
+//! Scope: this covers query execution and catalog/schema/table/table-type
+//! listing only. It does not implement `CommandGetSqlInfo`, cross-table
+//! constraints/primary/foreign keys, transactions, update statements,
+//! substrait plans over Flight, or the `table_schema` column of
+//! `CommandGetTables` (serializing each table's Arrow schema as IPC bytes).
+//! `ActionCreatePreparedStatementResult`'s `dataset_schema`/
+//! `parameter_schema` are also left empty rather than calling
+//! [StatementApi::get_param_schema] -- a client that needs them should
+//! derive them from the first `do_get` response instead. Everything else
+//! falls through to [FlightSqlService]'s default `Unimplemented` behavior.
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::Mutex;
+
+use arrow::array::{Array, ListArray, RecordBatch, StringArray, StructArray};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use arrow_flight::encode::FlightDataEncoderBuilder;
+use arrow_flight::error::FlightError;
+use arrow_flight::flight_service_server::FlightService;
+use arrow_flight::sql::server::FlightSqlService;
+use arrow_flight::sql::{
+    ActionClosePreparedStatementRequest, ActionCreatePreparedStatementRequest,
+    ActionCreatePreparedStatementResult, CommandGetCatalogs, CommandGetDbSchemas,
+    CommandGetTableTypes, CommandGetTables, CommandPreparedStatementQuery, CommandStatementQuery,
+    ProstMessageExt, TicketStatementQuery,
+};
+use arrow_flight::{
+    Action, FlightData, FlightDescriptor, FlightEndpoint, FlightInfo, HandshakeRequest,
+    HandshakeResponse, Ticket,
+};
+use futures::Stream;
+use prost::Message;
+use tonic::{Request, Response, Status, Streaming};
+
+use crate::driver_manager::r#async::AsyncConnection;
+use crate::ffi::AdbcObjectDepth;
+use crate::interface::StatementApi;
+
+/// The stream type every `do_get_*`/`do_handshake` method below returns.
+type FlightDataStream = Pin<Box<dyn Stream<Item = Result<FlightData, Status>> + Send>>;
+
+fn status_of(err: crate::driver_manager::Error) -> Status {
+    Status::internal(err.to_string())
+}
+
+/// Encode `batches` (all sharing `schema`) as the [FlightDataStream] a
+/// `do_get_*` handler returns.
+fn encode_batches(schema: SchemaRef, batches: Vec<RecordBatch>) -> Result<FlightDataStream, Status> {
+    let stream = futures::stream::iter(batches.into_iter().map(Ok::<_, FlightError>));
+    let flight_data = FlightDataEncoderBuilder::new()
+        .with_schema(schema)
+        .build(stream)
+        .map(|result| result.map_err(|err| Status::internal(err.to_string())));
+    Ok(Box::pin(flight_data))
+}
+
+/// Build a single-endpoint [FlightInfo] whose ticket is `cmd`'s own
+/// serialized bytes, re-issued verbatim to the matching `do_get_*` call.
+fn flight_info_for<C: ProstMessageExt>(
+    cmd: &C,
+    descriptor: FlightDescriptor,
+    schema: &Schema,
+) -> Result<FlightInfo, Status> {
+    let ticket = Ticket::new(cmd.as_any().encode_to_vec());
+    let endpoint = FlightEndpoint::new().with_ticket(ticket);
+    FlightInfo::new()
+        .try_with_schema(schema)
+        .map_err(|err| Status::internal(err.to_string()))
+        .map(|info| {
+            info.with_descriptor(descriptor)
+                .with_endpoint(endpoint)
+        })
+}
+
+/// One in-flight prepared statement: the query text it was created with.
+struct PreparedStatement {
+    query: String,
+}
+
+/// A Flight SQL server bridging to a single ADBC connection.
+///
+/// Each query (prepared or not) gets its own fresh [AsyncStatement] created
+/// from [AsyncConnection::new_statement]; this crate does not give ADBC
+/// statements their own identity beyond that, so `handle` in
+/// [CommandPreparedStatementQuery] is just an opaque counter tracked here.
+pub struct AdbcFlightSqlService {
+    connection: AsyncConnection,
+    prepared_statements: Mutex<HashMap<Vec<u8>, PreparedStatement>>,
+    next_handle: Mutex<u64>,
+}
+
+impl AdbcFlightSqlService {
+    pub fn new(connection: AsyncConnection) -> Self {
+        Self {
+            connection,
+            prepared_statements: Mutex::new(HashMap::new()),
+            next_handle: Mutex::new(0),
+        }
+    }
+
+    /// Run `query` on a fresh statement and collect its result into
+    /// [RecordBatch]es, along with the schema DataFusion/Flight SQL should
+    /// advertise for it.
+    async fn run_query(&self, query: &str) -> Result<(SchemaRef, Vec<RecordBatch>), Status> {
+        let statement = self.connection.new_statement().await.map_err(status_of)?;
+        statement
+            .set_sql_query(query.to_string())
+            .await
+            .map_err(status_of)?;
+        statement.prepare().await.map_err(status_of)?;
+        let result = statement.execute().await.map_err(status_of)?;
+        let Some(mut stream) = result.result else {
+            return Ok((SchemaRef::new(Schema::empty()), Vec::new()));
+        };
+        let mut batches = Vec::new();
+        let mut schema = None;
+        loop {
+            let Some(batch) = stream.next_batch().await else {
+                break;
+            };
+            let batch = batch.map_err(|err| Status::internal(err.to_string()))?;
+            if schema.is_none() {
+                schema = Some(batch.schema());
+            }
+            batches.push(batch);
+        }
+        let schema = schema.unwrap_or_else(|| SchemaRef::new(Schema::empty()));
+        Ok((schema, batches))
+    }
+
+    async fn get_objects_batches(
+        &self,
+        depth: AdbcObjectDepth,
+        catalog: Option<String>,
+        db_schema: Option<String>,
+        table_name: Option<String>,
+    ) -> Result<Vec<RecordBatch>, Status> {
+        let mut stream = self
+            .connection
+            .get_objects(depth, catalog, db_schema, table_name, None)
+            .await
+            .map_err(status_of)?;
+        let mut batches = Vec::new();
+        while let Some(batch) = stream.next_batch().await {
+            batches.push(batch.map_err(|err| Status::internal(err.to_string()))?);
+        }
+        Ok(batches)
+    }
+}
+
+/// Flatten `get_objects`'s nested `catalog_name`/`catalog_db_schemas`
+/// result into Flight SQL's `CommandGetCatalogs` schema (`catalog_name:
+/// utf8 not null`).
+fn flatten_catalogs(batches: &[RecordBatch]) -> RecordBatch {
+    let mut names = Vec::new();
+    for batch in batches {
+        let catalog_name = string_column(batch, "catalog_name");
+        for i in 0..batch.num_rows() {
+            if let Some(name) = catalog_name.and_then(|col| col.is_valid(i).then(|| col.value(i)))
+            {
+                names.push(name.to_string());
+            }
+        }
+    }
+    let schema = catalogs_schema();
+    RecordBatch::try_new(schema, vec![std::sync::Arc::new(StringArray::from(names))])
+        .expect("catalogs batch shape is fixed")
+}
+
+/// Flatten `get_objects`'s nested result into Flight SQL's
+/// `CommandGetDbSchemas` schema (`catalog_name: utf8`, `db_schema_name:
+/// utf8 not null`).
+fn flatten_db_schemas(batches: &[RecordBatch]) -> RecordBatch {
+    let mut catalogs = Vec::new();
+    let mut db_schemas = Vec::new();
+    for batch in batches {
+        let catalog_name = string_column(batch, "catalog_name");
+        let catalog_db_schemas = list_column(batch, "catalog_db_schemas");
+        for i in 0..batch.num_rows() {
+            let catalog = catalog_name.and_then(|col| col.is_valid(i).then(|| col.value(i)));
+            let Some(schemas) = catalog_db_schemas.filter(|col| col.is_valid(i)) else {
+                continue;
+            };
+            let schemas = StructArray::from(schemas.value(i).to_data());
+            let schema_name = string_column_of(&schemas, "db_schema_name");
+            for j in 0..schemas.len() {
+                let Some(name) = schema_name.and_then(|col| col.is_valid(j).then(|| col.value(j)))
+                else {
+                    continue;
+                };
+                catalogs.push(catalog.map(str::to_string));
+                db_schemas.push(name.to_string());
+            }
+        }
+    }
+    let schema = db_schemas_schema();
+    RecordBatch::try_new(
+        schema,
+        vec![
+            std::sync::Arc::new(StringArray::from(catalogs)),
+            std::sync::Arc::new(StringArray::from(db_schemas)),
+        ],
+    )
+    .expect("db_schemas batch shape is fixed")
+}
+
+/// Flatten `get_objects`'s nested result into Flight SQL's
+/// `CommandGetTables` schema, minus the `table_schema` IPC column (see the
+/// module docs).
+fn flatten_tables(batches: &[RecordBatch]) -> RecordBatch {
+    let mut catalogs = Vec::new();
+    let mut db_schemas = Vec::new();
+    let mut table_names = Vec::new();
+    let mut table_types = Vec::new();
+    for batch in batches {
+        let catalog_name = string_column(batch, "catalog_name");
+        let catalog_db_schemas = list_column(batch, "catalog_db_schemas");
+        for i in 0..batch.num_rows() {
+            let catalog = catalog_name.and_then(|col| col.is_valid(i).then(|| col.value(i)));
+            let Some(schemas) = catalog_db_schemas.filter(|col| col.is_valid(i)) else {
+                continue;
+            };
+            let schemas = StructArray::from(schemas.value(i).to_data());
+            let schema_name = string_column_of(&schemas, "db_schema_name");
+            let schema_tables = list_column_of(&schemas, "db_schema_tables");
+            for j in 0..schemas.len() {
+                let db_schema = schema_name.and_then(|col| col.is_valid(j).then(|| col.value(j)));
+                let Some(tables) = schema_tables.filter(|col| col.is_valid(j)) else {
+                    continue;
+                };
+                let tables = StructArray::from(tables.value(j).to_data());
+                let table_name = string_column_of(&tables, "table_name");
+                let table_type = string_column_of(&tables, "table_type");
+                for k in 0..tables.len() {
+                    let (Some(name), Some(ty)) = (
+                        table_name.map(|col| col.value(k)),
+                        table_type.map(|col| col.value(k)),
+                    ) else {
+                        continue;
+                    };
+                    catalogs.push(catalog.map(str::to_string));
+                    db_schemas.push(db_schema.map(str::to_string));
+                    table_names.push(name.to_string());
+                    table_types.push(ty.to_string());
+                }
+            }
+        }
+    }
+    let schema = tables_schema();
+    RecordBatch::try_new(
+        schema,
+        vec![
+            std::sync::Arc::new(StringArray::from(catalogs)),
+            std::sync::Arc::new(StringArray::from(db_schemas)),
+            std::sync::Arc::new(StringArray::from(table_names)),
+            std::sync::Arc::new(StringArray::from(table_types)),
+        ],
+    )
+    .expect("tables batch shape is fixed")
+}
+
+fn string_column<'a>(batch: &'a RecordBatch, name: &str) -> Option<&'a StringArray> {
+    batch
+        .column_by_name(name)
+        .and_then(|col| col.as_any().downcast_ref::<StringArray>())
+}
+
+fn list_column<'a>(batch: &'a RecordBatch, name: &str) -> Option<&'a ListArray> {
+    batch
+        .column_by_name(name)
+        .and_then(|col| col.as_any().downcast_ref::<ListArray>())
+}
+
+fn string_column_of<'a>(array: &'a StructArray, name: &str) -> Option<&'a StringArray> {
+    array
+        .column_by_name(name)
+        .and_then(|col| col.as_any().downcast_ref::<StringArray>())
+}
+
+fn list_column_of<'a>(array: &'a StructArray, name: &str) -> Option<&'a ListArray> {
+    array
+        .column_by_name(name)
+        .and_then(|col| col.as_any().downcast_ref::<ListArray>())
+}
+
+fn catalogs_schema() -> SchemaRef {
+    SchemaRef::new(Schema::new(vec![Field::new(
+        "catalog_name",
+        DataType::Utf8,
+        false,
+    )]))
+}
+
+fn db_schemas_schema() -> SchemaRef {
+    SchemaRef::new(Schema::new(vec![
+        Field::new("catalog_name", DataType::Utf8, true),
+        Field::new("db_schema_name", DataType::Utf8, false),
+    ]))
+}
+
+fn tables_schema() -> SchemaRef {
+    SchemaRef::new(Schema::new(vec![
+        Field::new("catalog_name", DataType::Utf8, true),
+        Field::new("db_schema_name", DataType::Utf8, true),
+        Field::new("table_name", DataType::Utf8, false),
+        Field::new("table_type", DataType::Utf8, false),
+    ]))
+}
+
+fn table_types_schema() -> SchemaRef {
+    SchemaRef::new(Schema::new(vec![Field::new(
+        "table_type",
+        DataType::Utf8,
+        false,
+    )]))
+}
+
+#[tonic::async_trait]
+impl FlightSqlService for AdbcFlightSqlService {
+    type FlightService = Self;
+
+    /// ADBC authenticates at the connection level (via
+    /// [crate::interface::ConnectionApi::set_option]/driver-specific
+    /// options), not per Flight SQL session, so handshake is a no-op that
+    /// just echoes the client's token back.
+    async fn do_handshake(
+        &self,
+        request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<
+        Response<Pin<Box<dyn Stream<Item = Result<HandshakeResponse, Status>> + Send>>>,
+        Status,
+    > {
+        let payload = request
+            .into_inner()
+            .message()
+            .await?
+            .map(|req| req.payload)
+            .unwrap_or_default();
+        let response = HandshakeResponse {
+            protocol_version: 0,
+            payload,
+        };
+        Ok(Response::new(Box::pin(futures::stream::once(async move {
+            Ok(response)
+        }))))
+    }
+
+    async fn get_flight_info_statement(
+        &self,
+        query: CommandStatementQuery,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        // Results aren't cached between this call and `do_get_statement`, so
+        // the query actually runs twice per round trip; fine for read-only
+        // SQL, but a side-effecting statement would run twice too.
+        let (schema, _batches) = self.run_query(&query.query).await?;
+        let ticket = TicketStatementQuery {
+            statement_handle: query.query.clone().into_bytes().into(),
+        };
+        let info = flight_info_for(&ticket, request.into_inner(), &schema)?;
+        Ok(Response::new(info))
+    }
+
+    async fn do_get_statement(
+        &self,
+        ticket: TicketStatementQuery,
+        _request: Request<Ticket>,
+    ) -> Result<Response<FlightDataStream>, Status> {
+        let query = String::from_utf8(ticket.statement_handle.to_vec())
+            .map_err(|err| Status::invalid_argument(err.to_string()))?;
+        let (schema, batches) = self.run_query(&query).await?;
+        Ok(Response::new(encode_batches(schema, batches)?))
+    }
+
+    async fn get_flight_info_prepared_statement(
+        &self,
+        cmd: CommandPreparedStatementQuery,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let query = {
+            let prepared = self.prepared_statements.lock().unwrap();
+            prepared
+                .get(cmd.prepared_statement_handle.as_ref())
+                .map(|p| p.query.clone())
+                .ok_or_else(|| Status::not_found("unknown prepared statement handle"))?
+        };
+        let (schema, _batches) = self.run_query(&query).await?;
+        let info = flight_info_for(&cmd, request.into_inner(), &schema)?;
+        Ok(Response::new(info))
+    }
+
+    async fn do_get_prepared_statement(
+        &self,
+        cmd: CommandPreparedStatementQuery,
+        _request: Request<Ticket>,
+    ) -> Result<Response<FlightDataStream>, Status> {
+        let query = {
+            let prepared = self.prepared_statements.lock().unwrap();
+            prepared
+                .get(cmd.prepared_statement_handle.as_ref())
+                .map(|p| p.query.clone())
+                .ok_or_else(|| Status::not_found("unknown prepared statement handle"))?
+        };
+        let (schema, batches) = self.run_query(&query).await?;
+        Ok(Response::new(encode_batches(schema, batches)?))
+    }
+
+    async fn do_action_create_prepared_statement(
+        &self,
+        query: ActionCreatePreparedStatementRequest,
+        _request: Request<Action>,
+    ) -> Result<ActionCreatePreparedStatementResult, Status> {
+        let statement = self.connection.new_statement().await.map_err(status_of)?;
+        statement
+            .set_sql_query(query.query.clone())
+            .await
+            .map_err(status_of)?;
+        statement.prepare().await.map_err(status_of)?;
+
+        let handle = {
+            let mut next_handle = self.next_handle.lock().unwrap();
+            let handle = *next_handle;
+            *next_handle += 1;
+            handle
+        };
+        let handle_bytes = handle.to_be_bytes().to_vec();
+        self.prepared_statements.lock().unwrap().insert(
+            handle_bytes.clone(),
+            PreparedStatement { query: query.query },
+        );
+        Ok(ActionCreatePreparedStatementResult {
+            prepared_statement_handle: handle_bytes.into(),
+            dataset_schema: Vec::new().into(),
+            parameter_schema: Vec::new().into(),
+        })
+    }
+
+    async fn do_action_close_prepared_statement(
+        &self,
+        query: ActionClosePreparedStatementRequest,
+        _request: Request<Action>,
+    ) {
+        self.prepared_statements
+            .lock()
+            .unwrap()
+            .remove(query.prepared_statement_handle.as_ref());
+    }
+
+    async fn get_flight_info_catalogs(
+        &self,
+        query: CommandGetCatalogs,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let info = flight_info_for(&query, request.into_inner(), &catalogs_schema())?;
+        Ok(Response::new(info))
+    }
+
+    async fn do_get_catalogs(
+        &self,
+        _query: CommandGetCatalogs,
+        _request: Request<Ticket>,
+    ) -> Result<Response<FlightDataStream>, Status> {
+        let batches = self
+            .get_objects_batches(AdbcObjectDepth::Catalogs, None, None, None)
+            .await?;
+        let batch = flatten_catalogs(&batches);
+        Ok(Response::new(encode_batches(
+            catalogs_schema(),
+            vec![batch],
+        )?))
+    }
+
+    async fn get_flight_info_schemas(
+        &self,
+        query: CommandGetDbSchemas,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let info = flight_info_for(&query, request.into_inner(), &db_schemas_schema())?;
+        Ok(Response::new(info))
+    }
+
+    async fn do_get_schemas(
+        &self,
+        query: CommandGetDbSchemas,
+        _request: Request<Ticket>,
+    ) -> Result<Response<FlightDataStream>, Status> {
+        let batches = self
+            .get_objects_batches(
+                AdbcObjectDepth::DBSchemas,
+                query.catalog.clone(),
+                query.db_schema_filter_pattern.clone(),
+                None,
+            )
+            .await?;
+        let batch = flatten_db_schemas(&batches);
+        Ok(Response::new(encode_batches(
+            db_schemas_schema(),
+            vec![batch],
+        )?))
+    }
+
+    async fn get_flight_info_tables(
+        &self,
+        query: CommandGetTables,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let info = flight_info_for(&query, request.into_inner(), &tables_schema())?;
+        Ok(Response::new(info))
+    }
+
+    async fn do_get_tables(
+        &self,
+        query: CommandGetTables,
+        _request: Request<Ticket>,
+    ) -> Result<Response<FlightDataStream>, Status> {
+        let batches = self
+            .get_objects_batches(
+                AdbcObjectDepth::Tables,
+                query.catalog.clone(),
+                query.db_schema_filter_pattern.clone(),
+                query.table_name_filter_pattern.clone(),
+            )
+            .await?;
+        let batch = flatten_tables(&batches);
+        Ok(Response::new(encode_batches(tables_schema(), vec![batch])?))
+    }
+
+    async fn get_flight_info_table_types(
+        &self,
+        query: CommandGetTableTypes,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let info = flight_info_for(&query, request.into_inner(), &table_types_schema())?;
+        Ok(Response::new(info))
+    }
+
+    async fn do_get_table_types(
+        &self,
+        _query: CommandGetTableTypes,
+        _request: Request<Ticket>,
+    ) -> Result<Response<FlightDataStream>, Status> {
+        let table_types = self.connection.get_table_types().await.map_err(status_of)?;
+        let batch = RecordBatch::try_new(
+            table_types_schema(),
+            vec![std::sync::Arc::new(StringArray::from(table_types))],
+        )
+        .expect("table_types batch shape is fixed");
+        Ok(Response::new(encode_batches(
+            table_types_schema(),
+            vec![batch],
+        )?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ffi::AdbcObjectDepth;
+    use crate::objects::{get_objects_batch, CatalogInfo, ColumnInfo, DbSchemaInfo, TableInfo};
+
+    fn sample_catalogs() -> Vec<CatalogInfo> {
+        vec![
+            CatalogInfo {
+                catalog_name: Some("my_catalog".to_string()),
+                db_schemas: vec![DbSchemaInfo {
+                    db_schema_name: Some("my_schema".to_string()),
+                    tables: vec![
+                        TableInfo {
+                            table_name: "t1".to_string(),
+                            table_type: "BASE TABLE".to_string(),
+                            columns: vec![ColumnInfo {
+                                column_name: "id".to_string(),
+                                ordinal_position: Some(1),
+                                remarks: None,
+                            }],
+                            constraints: vec![],
+                        },
+                        TableInfo {
+                            table_name: "v1".to_string(),
+                            table_type: "VIEW".to_string(),
+                            columns: vec![],
+                            constraints: vec![],
+                        },
+                    ],
+                }],
+            },
+            CatalogInfo {
+                catalog_name: Some("other_catalog".to_string()),
+                db_schemas: vec![],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_flatten_catalogs() {
+        let batch = get_objects_batch(
+            AdbcObjectDepth::Catalogs,
+            None,
+            None,
+            None,
+            None,
+            None,
+            &sample_catalogs(),
+        )
+        .unwrap();
+
+        let flattened = flatten_catalogs(&[batch]);
+        assert_eq!(*flattened.schema(), *catalogs_schema());
+        let names = flattened
+            .column(0)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(
+            names.iter().map(|v| v.unwrap()).collect::<Vec<_>>(),
+            vec!["my_catalog", "other_catalog"]
+        );
+    }
+
+    #[test]
+    fn test_flatten_db_schemas() {
+        let batch = get_objects_batch(
+            AdbcObjectDepth::DBSchemas,
+            None,
+            None,
+            None,
+            None,
+            None,
+            &sample_catalogs(),
+        )
+        .unwrap();
+
+        let flattened = flatten_db_schemas(&[batch]);
+        assert_eq!(*flattened.schema(), *db_schemas_schema());
+        let catalogs = flattened
+            .column(0)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        let db_schemas = flattened
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(catalogs.iter().collect::<Vec<_>>(), vec![Some("my_catalog")]);
+        assert_eq!(db_schemas.iter().collect::<Vec<_>>(), vec![Some("my_schema")]);
+    }
+
+    #[test]
+    fn test_flatten_tables() {
+        let batch = get_objects_batch(
+            AdbcObjectDepth::Tables,
+            None,
+            None,
+            None,
+            None,
+            None,
+            &sample_catalogs(),
+        )
+        .unwrap();
+
+        let flattened = flatten_tables(&[batch]);
+        assert_eq!(*flattened.schema(), *tables_schema());
+        let table_names = flattened
+            .column(2)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        let table_types = flattened
+            .column(3)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(
+            table_names.iter().map(|v| v.unwrap()).collect::<Vec<_>>(),
+            vec!["t1", "v1"]
+        );
+        assert_eq!(
+            table_types.iter().map(|v| v.unwrap()).collect::<Vec<_>>(),
+            vec!["BASE TABLE", "VIEW"]
+        );
+    }
+}
@@ -0,0 +1,835 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Helpers for implementing an ADBC driver in Rust.
+//!
+//! A driver author implements [AdbcDatabaseImpl], [AdbcConnectionImpl], and
+//! [AdbcStatementImpl] (plus the [crate::interface] traits) on their own
+//! types, and then calls [adbc_init_func] to generate the `extern "C"`
+//! entrypoint that the driver manager loads via `dlopen`/`AdbcDriverInit`.
+use std::ffi::{c_char, c_void};
+use std::rc::Rc;
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, StringArray, StructArray};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::error::ArrowError;
+use arrow::ffi::{FFI_ArrowArray, FFI_ArrowSchema};
+use arrow::ffi_stream::{ArrowArrayStreamReader, FFI_ArrowArrayStream};
+use arrow::record_batch::{RecordBatch, RecordBatchIterator, RecordBatchReader};
+
+use crate::error::{ffi_message_to_string, AdbcError, AdbcStatusCode, FFI_AdbcError};
+use crate::ffi::{
+    AdbcObjectDepth, FFI_AdbcConnection, FFI_AdbcDatabase, FFI_AdbcDriver, FFI_AdbcPartitions,
+    FFI_AdbcStatement,
+};
+use crate::interface::{ConnectionApi, DatabaseApi, StatementApi};
+
+/// Driver-side hook run once a [DatabaseApi] implementation has had all of
+/// its initial options set via [DatabaseApi::set_option].
+pub trait AdbcDatabaseImpl: DatabaseApi + Default + Send + Sync {
+    /// Finish initializing the database, after all initial options have been set.
+    fn init(&self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// Driver-side hook run once a [ConnectionApi] implementation has had all of
+/// its initial options set via [ConnectionApi::set_option].
+pub trait AdbcConnectionImpl: ConnectionApi + Default {
+    /// The type of the database this connection is created from.
+    type DatabaseType: AdbcDatabaseImpl;
+
+    /// Finish initializing the connection against the given database.
+    fn init(&self, database: Arc<Self::DatabaseType>) -> Result<(), Self::Error>;
+}
+
+/// Driver-side constructor hook for a [StatementApi] implementation.
+pub trait AdbcStatementImpl: StatementApi {
+    /// The type of the connection this statement is created from.
+    type ConnectionType;
+
+    /// Construct a new statement tied to the given connection.
+    fn new_from_connection(connection: Rc<Self::ConnectionType>) -> Self;
+}
+
+/// Translate a driver-defined error into the ADBC FFI error-reporting convention:
+/// populate `error` (if non-null) and return the associated status code.
+pub(crate) fn set_error(
+    err: &impl AdbcError,
+    error: *mut FFI_AdbcError,
+) -> AdbcStatusCode {
+    if let Some(error) = unsafe { error.as_mut() } {
+        error.set(err);
+    }
+    err.status_code()
+}
+
+/// Box up a driver-defined `Default`-constructed value so it can be stashed
+/// in an FFI struct's `private_data` pointer.
+pub(crate) fn boxed_private_data<T>(value: T) -> *mut c_void {
+    Box::into_raw(Box::new(value)) as *mut c_void
+}
+
+/// Recover a previously-boxed value from an FFI struct's `private_data` pointer.
+///
+/// # Safety
+/// `ptr` must have been produced by [boxed_private_data] with the same `T`,
+/// and must not be accessed again after this call (the box is dropped).
+pub(crate) unsafe fn take_private_data<T>(ptr: *mut c_void) -> Box<T> {
+    Box::from_raw(ptr as *mut T)
+}
+
+/// Generate the `extern "C"` `AdbcDriverInit` entrypoint for a driver whose
+/// statement type is `$statement`.
+///
+/// This wires up a [crate::ffi::FFI_AdbcDriver] whose function pointers
+/// downcast `private_data` back to the driver's `Database`/`Connection`/
+/// `Statement` types (inferred via [AdbcStatementImpl::ConnectionType] and
+/// [AdbcConnectionImpl::DatabaseType]) and call the corresponding
+/// [crate::interface] trait method, translating the `Result` back across
+/// the FFI boundary with [set_error].
+///
+/// Most of the glue is mechanical (marshal a C string in, marshal a
+/// `RecordBatchReader` out as an `FFI_ArrowArrayStream`, etc.) and is
+/// identical for every driver, so it lives behind this macro rather than
+/// being hand-written by each driver author.
+#[macro_export]
+macro_rules! adbc_init_func {
+    ($name:ident, $statement:ty) => {
+        #[allow(non_snake_case)]
+        #[no_mangle]
+        pub unsafe extern "C" fn $name(
+            version: ::std::os::raw::c_int,
+            raw_driver: *mut ::std::ffi::c_void,
+            error: *mut $crate::error::FFI_AdbcError,
+        ) -> $crate::error::AdbcStatusCode {
+            $crate::implement::init_driver::<
+                <$statement as $crate::implement::AdbcStatementImpl>::ConnectionType,
+                <<$statement as $crate::implement::AdbcStatementImpl>::ConnectionType as $crate::implement::AdbcConnectionImpl>::DatabaseType,
+                $statement,
+            >(version, raw_driver, error)
+        }
+    };
+}
+
+/// The real implementation backing [adbc_init_func], monomorphized over the
+/// driver's `Connection`, `Database`, and `Statement` types.
+pub fn init_driver<C, D, S>(
+    version: i32,
+    raw_driver: *mut c_void,
+    error: *mut FFI_AdbcError,
+) -> AdbcStatusCode
+where
+    D: AdbcDatabaseImpl,
+    C: AdbcConnectionImpl<DatabaseType = D>,
+    S: AdbcStatementImpl<ConnectionType = C>,
+{
+    use crate::{ADBC_VERSION_1_0_0, ADBC_VERSION_1_1_0};
+
+    if version != ADBC_VERSION_1_0_0 && version != ADBC_VERSION_1_1_0 {
+        return AdbcStatusCode::NotImplemented;
+    }
+    let driver = unsafe { (raw_driver as *mut FFI_AdbcDriver).as_mut() };
+    let Some(driver) = driver else {
+        return AdbcStatusCode::InvalidArgument;
+    };
+
+    // Wire the vtable slots this module knows how to dispatch generically
+    // over `D`, `C`, and `S`. The rest are left as whatever
+    // `FFI_AdbcDriver::empty` already populated (a `NotImplemented` stub),
+    // for a later `init_driver` extension to fill in.
+    driver.database_new = Some(dispatch::database_new::<D>);
+    driver.database_init = Some(dispatch::database_init::<D>);
+    driver.database_set_option = Some(dispatch::database_set_option::<D>);
+    driver.database_release = Some(dispatch::database_release::<D>);
+    driver.connection_new = Some(dispatch::connection_new::<C>);
+    driver.connection_init = Some(dispatch::connection_init::<C>);
+    driver.connection_set_option = Some(dispatch::connection_set_option::<C>);
+    driver.connection_release = Some(dispatch::connection_release::<C>);
+    driver.connection_get_objects = Some(dispatch::connection_get_objects::<C>);
+    driver.connection_get_info = Some(dispatch::connection_get_info::<C>);
+    driver.connection_get_table_schema = Some(dispatch::connection_get_table_schema::<C>);
+    driver.connection_get_table_types = Some(dispatch::connection_get_table_types::<C>);
+    driver.connection_read_partition = Some(dispatch::connection_read_partition::<C>);
+    driver.connection_commit = Some(dispatch::connection_commit::<C>);
+    driver.connection_rollback = Some(dispatch::connection_rollback::<C>);
+    driver.statement_new = Some(dispatch::statement_new::<S>);
+    driver.statement_release = Some(dispatch::statement_release::<S>);
+    driver.statement_set_option = Some(dispatch::statement_set_option::<S>);
+    driver.statement_set_sql_query = Some(dispatch::statement_set_sql_query::<S>);
+    driver.statement_set_substrait_plan = Some(dispatch::statement_set_substrait_plan::<S>);
+    driver.statement_prepare = Some(dispatch::statement_prepare::<S>);
+    driver.statement_execute_query = Some(dispatch::statement_execute_query::<S>);
+    driver.statement_execute_partitions = Some(dispatch::statement_execute_partitions::<S>);
+    driver.statement_get_parameter_schema = Some(dispatch::statement_get_parameter_schema::<S>);
+    driver.statement_bind = Some(dispatch::statement_bind::<S>);
+    driver.statement_bind_stream = Some(dispatch::statement_bind_stream::<S>);
+    driver.private_manager = std::ptr::null_mut();
+    let _ = error;
+    AdbcStatusCode::Ok
+}
+
+/// Generic `extern "C"` dispatch functions that downcast an FFI struct's
+/// `private_data` back to the driver-author's `D`/`C`/`S` type and call the
+/// matching [crate::interface] trait method, translating the result back
+/// across the FFI boundary with [set_error].
+///
+/// `init_driver` monomorphizes one copy of each function per driver (since
+/// each driver has its own concrete `D`, `C`, `S`) and stores the resulting
+/// function pointers in the vtable, so this is the only place a driver
+/// author's types and the raw FFI signatures meet.
+mod dispatch {
+    use super::*;
+
+    /// A database's `private_data` holds an `Arc<D>` (boxed) rather than a
+    /// bare `D`, so that [connection_init] can clone a reference to hand to
+    /// [AdbcConnectionImpl::init] without taking it away from the database.
+    unsafe fn database_impl<D>(database: &FFI_AdbcDatabase) -> &Arc<D> {
+        unsafe { &*(database.private_data as *const Arc<D>) }
+    }
+
+    /// A connection's `private_data` holds an `Rc<C>` (boxed) rather than a
+    /// bare `C`, so that [statement_new] can clone a reference to hand to
+    /// [AdbcStatementImpl::new_from_connection] without taking it away from
+    /// the connection.
+    unsafe fn connection_impl<C>(connection: &FFI_AdbcConnection) -> &Rc<C> {
+        unsafe { &*(connection.private_data as *const Rc<C>) }
+    }
+
+    unsafe fn statement_impl<S>(statement: &FFI_AdbcStatement) -> &mut S {
+        unsafe { &mut *(statement.private_data as *mut S) }
+    }
+
+    pub(super) unsafe extern "C" fn database_new<D: AdbcDatabaseImpl>(
+        database: *mut FFI_AdbcDatabase,
+        _error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        let Some(database) = (unsafe { database.as_mut() }) else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        database.private_data = boxed_private_data(Arc::new(D::default()));
+        AdbcStatusCode::Ok
+    }
+
+    pub(super) unsafe extern "C" fn database_init<D: AdbcDatabaseImpl>(
+        database: *mut FFI_AdbcDatabase,
+        error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        let Some(database) = (unsafe { database.as_ref() }) else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        match unsafe { database_impl::<D>(database) }.init() {
+            Ok(()) => AdbcStatusCode::Ok,
+            Err(err) => set_error(&err, error),
+        }
+    }
+
+    pub(super) unsafe extern "C" fn database_set_option<D: AdbcDatabaseImpl>(
+        database: *mut FFI_AdbcDatabase,
+        key: *const c_char,
+        value: *const c_char,
+        error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        let Some(database) = (unsafe { database.as_ref() }) else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        let (Some(key), Some(value)) =
+            (unsafe { ffi_message_to_string(key) }, unsafe { ffi_message_to_string(value) })
+        else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        match unsafe { database_impl::<D>(database) }.set_option(&key, &value) {
+            Ok(()) => AdbcStatusCode::Ok,
+            Err(err) => set_error(&err, error),
+        }
+    }
+
+    pub(super) unsafe extern "C" fn database_release<D: AdbcDatabaseImpl>(
+        database: *mut FFI_AdbcDatabase,
+        _error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        let Some(database) = (unsafe { database.as_mut() }) else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        if !database.private_data.is_null() {
+            drop(unsafe { take_private_data::<Arc<D>>(database.private_data) });
+            database.private_data = std::ptr::null_mut();
+        }
+        AdbcStatusCode::Ok
+    }
+
+    pub(super) unsafe extern "C" fn connection_new<C: AdbcConnectionImpl>(
+        connection: *mut FFI_AdbcConnection,
+        _error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        let Some(connection) = (unsafe { connection.as_mut() }) else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        connection.private_data = boxed_private_data(Rc::new(C::default()));
+        AdbcStatusCode::Ok
+    }
+
+    pub(super) unsafe extern "C" fn connection_init<C: AdbcConnectionImpl>(
+        connection: *mut FFI_AdbcConnection,
+        database: *mut FFI_AdbcDatabase,
+        error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        let (Some(connection), Some(database)) =
+            (unsafe { connection.as_ref() }, unsafe { database.as_ref() })
+        else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        let database = unsafe { database_impl::<C::DatabaseType>(database) }.clone();
+        match unsafe { connection_impl::<C>(connection) }.init(database) {
+            Ok(()) => AdbcStatusCode::Ok,
+            Err(err) => set_error(&err, error),
+        }
+    }
+
+    pub(super) unsafe extern "C" fn connection_set_option<C: AdbcConnectionImpl>(
+        connection: *mut FFI_AdbcConnection,
+        key: *const c_char,
+        value: *const c_char,
+        error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        let Some(connection) = (unsafe { connection.as_ref() }) else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        let (Some(key), Some(value)) =
+            (unsafe { ffi_message_to_string(key) }, unsafe { ffi_message_to_string(value) })
+        else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        match unsafe { connection_impl::<C>(connection) }.set_option(&key, &value) {
+            Ok(()) => AdbcStatusCode::Ok,
+            Err(err) => set_error(&err, error),
+        }
+    }
+
+    pub(super) unsafe extern "C" fn connection_release<C: AdbcConnectionImpl>(
+        connection: *mut FFI_AdbcConnection,
+        _error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        let Some(connection) = (unsafe { connection.as_mut() }) else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        if !connection.private_data.is_null() {
+            drop(unsafe { take_private_data::<Rc<C>>(connection.private_data) });
+            connection.private_data = std::ptr::null_mut();
+        }
+        AdbcStatusCode::Ok
+    }
+
+    /// Decode the raw FFI arguments (including the NUL-terminated
+    /// `table_type` array) and hand them to [ConnectionApi::get_objects].
+    /// A driver's implementation will typically build its result via
+    /// [crate::objects::get_objects_batch], which applies the SQL-LIKE
+    /// filters and `depth` truncation that method's contract requires.
+    pub(super) unsafe extern "C" fn connection_get_objects<C: AdbcConnectionImpl>(
+        connection: *mut FFI_AdbcConnection,
+        depth: AdbcObjectDepth,
+        catalog: *const c_char,
+        db_schema: *const c_char,
+        table_name: *const c_char,
+        table_type: *const *const c_char,
+        column_name: *const c_char,
+        out: *mut FFI_ArrowArrayStream,
+        error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        let Some(connection) = (unsafe { connection.as_ref() }) else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        let catalog = unsafe { ffi_message_to_string(catalog) };
+        let db_schema = unsafe { ffi_message_to_string(db_schema) };
+        let table_name = unsafe { ffi_message_to_string(table_name) };
+        let column_name = unsafe { ffi_message_to_string(column_name) };
+
+        // `table_type` is a NULL-terminated array of NUL-terminated C
+        // strings, or a NULL pointer entirely if the caller doesn't want to
+        // filter by table type.
+        let table_types: Option<Vec<String>> = if table_type.is_null() {
+            None
+        } else {
+            let mut types = Vec::new();
+            let mut cursor = table_type;
+            loop {
+                let entry = unsafe { *cursor };
+                if entry.is_null() {
+                    break;
+                }
+                types.extend(unsafe { ffi_message_to_string(entry) });
+                cursor = unsafe { cursor.add(1) };
+            }
+            Some(types)
+        };
+        let table_type_refs: Option<Vec<&str>> = table_types
+            .as_ref()
+            .map(|types| types.iter().map(String::as_str).collect());
+
+        let result = unsafe { connection_impl::<C>(connection) }.get_objects(
+            depth,
+            catalog.as_deref(),
+            db_schema.as_deref(),
+            table_name.as_deref(),
+            table_type_refs.as_deref(),
+            column_name.as_deref(),
+        );
+        match result {
+            Ok(reader) => {
+                if !out.is_null() {
+                    let stream = FFI_ArrowArrayStream::new(SendableReader(reader));
+                    unsafe { std::ptr::write(out, stream) };
+                }
+                AdbcStatusCode::Ok
+            }
+            Err(err) => set_error(&err, error),
+        }
+    }
+
+    /// Hand `info_codes` to [ConnectionApi::get_info] and marshal the result
+    /// back the same way [connection_get_objects] does.
+    pub(super) unsafe extern "C" fn connection_get_info<C: AdbcConnectionImpl>(
+        connection: *mut FFI_AdbcConnection,
+        info_codes: *const u32,
+        info_codes_length: usize,
+        out: *mut FFI_ArrowArrayStream,
+        error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        let Some(connection) = (unsafe { connection.as_ref() }) else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        let info_codes = if info_codes.is_null() {
+            &[][..]
+        } else {
+            unsafe { std::slice::from_raw_parts(info_codes, info_codes_length) }
+        };
+        match unsafe { connection_impl::<C>(connection) }.get_info(info_codes) {
+            Ok(reader) => {
+                if !out.is_null() {
+                    let stream = FFI_ArrowArrayStream::new(SendableReader(reader));
+                    unsafe { std::ptr::write(out, stream) };
+                }
+                AdbcStatusCode::Ok
+            }
+            Err(err) => set_error(&err, error),
+        }
+    }
+
+    pub(super) unsafe extern "C" fn connection_get_table_schema<C: AdbcConnectionImpl>(
+        connection: *mut FFI_AdbcConnection,
+        catalog: *const c_char,
+        db_schema: *const c_char,
+        table_name: *const c_char,
+        schema: *mut FFI_ArrowSchema,
+        error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        let Some(connection) = (unsafe { connection.as_ref() }) else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        let catalog = unsafe { ffi_message_to_string(catalog) };
+        let db_schema = unsafe { ffi_message_to_string(db_schema) };
+        let Some(table_name) = (unsafe { ffi_message_to_string(table_name) }) else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        let result = unsafe { connection_impl::<C>(connection) }.get_table_schema(
+            catalog.as_deref(),
+            db_schema.as_deref(),
+            &table_name,
+        );
+        match result {
+            Ok(result_schema) => {
+                if !schema.is_null() {
+                    match FFI_ArrowSchema::try_from(&result_schema) {
+                        Ok(ffi_schema) => unsafe { std::ptr::write(schema, ffi_schema) },
+                        Err(err) => return set_error(&ArrowMarshalError(err.to_string()), error),
+                    }
+                }
+                AdbcStatusCode::Ok
+            }
+            Err(err) => set_error(&err, error),
+        }
+    }
+
+    /// [ConnectionApi::get_table_types] returns a bare `Vec<String>` rather
+    /// than a reader, so this builds the single-column, single-batch stream
+    /// the FFI side of `connection_get_table_types` is documented to return.
+    pub(super) unsafe extern "C" fn connection_get_table_types<C: AdbcConnectionImpl>(
+        connection: *mut FFI_AdbcConnection,
+        out: *mut FFI_ArrowArrayStream,
+        error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        let Some(connection) = (unsafe { connection.as_ref() }) else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        match unsafe { connection_impl::<C>(connection) }.get_table_types() {
+            Ok(table_types) => {
+                if !out.is_null() {
+                    let schema = Arc::new(Schema::new(vec![Field::new(
+                        "table_type",
+                        DataType::Utf8,
+                        false,
+                    )]));
+                    let batch = RecordBatch::try_new(
+                        schema.clone(),
+                        vec![Arc::new(StringArray::from(table_types)) as ArrayRef],
+                    );
+                    let batch = match batch {
+                        Ok(batch) => batch,
+                        Err(err) => return set_error(&ArrowMarshalError(err.to_string()), error),
+                    };
+                    let reader = RecordBatchIterator::new(vec![Ok(batch)].into_iter(), schema);
+                    let stream = FFI_ArrowArrayStream::new(SendableReader(Box::new(reader)));
+                    unsafe { std::ptr::write(out, stream) };
+                }
+                AdbcStatusCode::Ok
+            }
+            Err(err) => set_error(&err, error),
+        }
+    }
+
+    pub(super) unsafe extern "C" fn connection_read_partition<C: AdbcConnectionImpl>(
+        connection: *mut FFI_AdbcConnection,
+        partition: *const u8,
+        partition_length: usize,
+        out: *mut FFI_ArrowArrayStream,
+        error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        let Some(connection) = (unsafe { connection.as_ref() }) else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        let partition = if partition.is_null() {
+            &[][..]
+        } else {
+            unsafe { std::slice::from_raw_parts(partition, partition_length) }
+        };
+        match unsafe { connection_impl::<C>(connection) }.read_partition(partition) {
+            Ok(reader) => {
+                if !out.is_null() {
+                    let stream = FFI_ArrowArrayStream::new(SendableReader(reader));
+                    unsafe { std::ptr::write(out, stream) };
+                }
+                AdbcStatusCode::Ok
+            }
+            Err(err) => set_error(&err, error),
+        }
+    }
+
+    pub(super) unsafe extern "C" fn connection_commit<C: AdbcConnectionImpl>(
+        connection: *mut FFI_AdbcConnection,
+        error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        let Some(connection) = (unsafe { connection.as_ref() }) else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        match unsafe { connection_impl::<C>(connection) }.commit() {
+            Ok(()) => AdbcStatusCode::Ok,
+            Err(err) => set_error(&err, error),
+        }
+    }
+
+    pub(super) unsafe extern "C" fn connection_rollback<C: AdbcConnectionImpl>(
+        connection: *mut FFI_AdbcConnection,
+        error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        let Some(connection) = (unsafe { connection.as_ref() }) else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        match unsafe { connection_impl::<C>(connection) }.rollback() {
+            Ok(()) => AdbcStatusCode::Ok,
+            Err(err) => set_error(&err, error),
+        }
+    }
+
+    pub(super) unsafe extern "C" fn statement_new<S: AdbcStatementImpl>(
+        connection: *mut FFI_AdbcConnection,
+        statement: *mut FFI_AdbcStatement,
+        _error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        let (Some(connection), Some(statement)) =
+            (unsafe { connection.as_ref() }, unsafe { statement.as_mut() })
+        else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        let connection = unsafe { connection_impl::<S::ConnectionType>(connection) }.clone();
+        statement.private_data = boxed_private_data(S::new_from_connection(connection));
+        AdbcStatusCode::Ok
+    }
+
+    pub(super) unsafe extern "C" fn statement_release<S: AdbcStatementImpl>(
+        statement: *mut FFI_AdbcStatement,
+        _error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        let Some(statement) = (unsafe { statement.as_mut() }) else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        if !statement.private_data.is_null() {
+            drop(unsafe { take_private_data::<S>(statement.private_data) });
+            statement.private_data = std::ptr::null_mut();
+        }
+        AdbcStatusCode::Ok
+    }
+
+    pub(super) unsafe extern "C" fn statement_set_option<S: AdbcStatementImpl>(
+        statement: *mut FFI_AdbcStatement,
+        key: *const c_char,
+        value: *const c_char,
+        error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        let Some(statement) = (unsafe { statement.as_mut() }) else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        let (Some(key), Some(value)) =
+            (unsafe { ffi_message_to_string(key) }, unsafe { ffi_message_to_string(value) })
+        else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        match unsafe { statement_impl::<S>(statement) }.set_option(&key, &value) {
+            Ok(()) => AdbcStatusCode::Ok,
+            Err(err) => set_error(&err, error),
+        }
+    }
+
+    pub(super) unsafe extern "C" fn statement_set_sql_query<S: AdbcStatementImpl>(
+        statement: *mut FFI_AdbcStatement,
+        query: *const c_char,
+        error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        let Some(statement) = (unsafe { statement.as_mut() }) else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        let Some(query) = (unsafe { ffi_message_to_string(query) }) else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        match unsafe { statement_impl::<S>(statement) }.set_sql_query(&query) {
+            Ok(()) => AdbcStatusCode::Ok,
+            Err(err) => set_error(&err, error),
+        }
+    }
+
+    /// Hand the raw Substrait plan bytes, undecoded, to
+    /// [StatementApi::set_substrait_plan]. Decoding into
+    /// [crate::substrait::Plan] (when the `substrait` feature is on) is left
+    /// to the driver itself, since `set_substrait_plan` takes the plan as
+    /// raw bytes -- a pure-SQL driver that never calls
+    /// [crate::substrait::Plan::decode_bytes] can return `NotImplemented`
+    /// from its own `set_substrait_plan` without this shim needing to know
+    /// the difference.
+    pub(super) unsafe extern "C" fn statement_set_substrait_plan<S: AdbcStatementImpl>(
+        statement: *mut FFI_AdbcStatement,
+        plan: *const u8,
+        length: usize,
+        error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        let Some(statement) = (unsafe { statement.as_mut() }) else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        if plan.is_null() && length != 0 {
+            return AdbcStatusCode::InvalidArgument;
+        }
+        let plan = if plan.is_null() {
+            &[]
+        } else {
+            unsafe { std::slice::from_raw_parts(plan, length) }
+        };
+        match unsafe { statement_impl::<S>(statement) }.set_substrait_plan(plan) {
+            Ok(()) => AdbcStatusCode::Ok,
+            Err(err) => set_error(&err, error),
+        }
+    }
+
+    pub(super) unsafe extern "C" fn statement_prepare<S: AdbcStatementImpl>(
+        statement: *mut FFI_AdbcStatement,
+        error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        let Some(statement) = (unsafe { statement.as_mut() }) else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        match unsafe { statement_impl::<S>(statement) }.prepare() {
+            Ok(()) => AdbcStatusCode::Ok,
+            Err(err) => set_error(&err, error),
+        }
+    }
+
+    pub(super) unsafe extern "C" fn statement_execute_query<S: AdbcStatementImpl>(
+        statement: *mut FFI_AdbcStatement,
+        out: *mut FFI_ArrowArrayStream,
+        rows_affected: *mut i64,
+        error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        let Some(statement) = (unsafe { statement.as_mut() }) else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        match unsafe { statement_impl::<S>(statement) }.execute() {
+            Ok(result) => {
+                if !rows_affected.is_null() {
+                    unsafe { *rows_affected = result.rows_affected };
+                }
+                if let Some(reader) = result.result {
+                    if !out.is_null() {
+                        let stream = FFI_ArrowArrayStream::new(SendableReader(reader));
+                        unsafe { std::ptr::write(out, stream) };
+                    }
+                }
+                AdbcStatusCode::Ok
+            }
+            Err(err) => set_error(&err, error),
+        }
+    }
+
+    pub(super) unsafe extern "C" fn statement_execute_partitions<S: AdbcStatementImpl>(
+        statement: *mut FFI_AdbcStatement,
+        schema: *mut FFI_ArrowSchema,
+        partitions: *mut FFI_AdbcPartitions,
+        rows_affected: *mut i64,
+        error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        let Some(statement) = (unsafe { statement.as_mut() }) else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        match unsafe { statement_impl::<S>(statement) }.execute_partitioned() {
+            Ok(result) => {
+                if !rows_affected.is_null() {
+                    unsafe { *rows_affected = result.rows_affected };
+                }
+                if !schema.is_null() {
+                    match FFI_ArrowSchema::try_from(&result.schema) {
+                        Ok(ffi_schema) => unsafe { std::ptr::write(schema, ffi_schema) },
+                        Err(err) => return set_error(&ArrowMarshalError(err.to_string()), error),
+                    }
+                }
+                if !partitions.is_null() {
+                    let ffi_partitions: FFI_AdbcPartitions = result.partition_ids.into();
+                    unsafe { std::ptr::write(partitions, ffi_partitions) };
+                }
+                AdbcStatusCode::Ok
+            }
+            Err(err) => set_error(&err, error),
+        }
+    }
+
+    pub(super) unsafe extern "C" fn statement_get_parameter_schema<S: AdbcStatementImpl>(
+        statement: *mut FFI_AdbcStatement,
+        schema: *mut FFI_ArrowSchema,
+        error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        let Some(statement) = (unsafe { statement.as_mut() }) else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        match unsafe { statement_impl::<S>(statement) }.get_param_schema() {
+            Ok(result) => {
+                if !schema.is_null() {
+                    match FFI_ArrowSchema::try_from(&result) {
+                        Ok(ffi_schema) => unsafe { std::ptr::write(schema, ffi_schema) },
+                        Err(err) => return set_error(&ArrowMarshalError(err.to_string()), error),
+                    }
+                }
+                AdbcStatusCode::Ok
+            }
+            Err(err) => set_error(&err, error),
+        }
+    }
+
+    pub(super) unsafe extern "C" fn statement_bind<S: AdbcStatementImpl>(
+        statement: *mut FFI_AdbcStatement,
+        array: *mut FFI_ArrowArray,
+        schema: *mut FFI_ArrowSchema,
+        error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        let Some(statement) = (unsafe { statement.as_mut() }) else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        let array = unsafe { std::ptr::read(array) };
+        let schema = unsafe { std::ptr::read(schema) };
+        let batch = (|| {
+            let array_data = unsafe { arrow::ffi::from_ffi(array, &schema) }?;
+            RecordBatch::try_from(StructArray::from(array_data))
+        })();
+        match batch {
+            Ok(batch) => match unsafe { statement_impl::<S>(statement) }.bind_data(batch) {
+                Ok(()) => AdbcStatusCode::Ok,
+                Err(err) => set_error(&err, error),
+            },
+            Err(err) => set_error(&ArrowMarshalError(err.to_string()), error),
+        }
+    }
+
+    pub(super) unsafe extern "C" fn statement_bind_stream<S: AdbcStatementImpl>(
+        statement: *mut FFI_AdbcStatement,
+        stream: *mut FFI_ArrowArrayStream,
+        error: *mut FFI_AdbcError,
+    ) -> AdbcStatusCode {
+        let Some(statement) = (unsafe { statement.as_mut() }) else {
+            return AdbcStatusCode::InvalidArgument;
+        };
+        let stream = unsafe { std::ptr::read(stream) };
+        match ArrowArrayStreamReader::try_new(stream) {
+            Ok(reader) => match unsafe { statement_impl::<S>(statement) }
+                .bind_stream(Box::new(reader))
+            {
+                Ok(()) => AdbcStatusCode::Ok,
+                Err(err) => set_error(&err, error),
+            },
+            Err(err) => set_error(&ArrowMarshalError(err.to_string()), error),
+        }
+    }
+
+    /// Wraps an [ArrowError] surfaced while marshalling Arrow data across the
+    /// FFI boundary (exporting a schema, or importing a bound parameter
+    /// array/stream), so it can go through [set_error] like any other
+    /// driver-reported error.
+    struct ArrowMarshalError(String);
+
+    impl AdbcError for ArrowMarshalError {
+        fn message(&self) -> &str {
+            &self.0
+        }
+
+        fn status_code(&self) -> AdbcStatusCode {
+            AdbcStatusCode::Internal
+        }
+    }
+
+    /// A driver's [crate::interface::StatementResult::result] is a
+    /// `Box<dyn RecordBatchReader>`, which is not `Send` in general (many
+    /// drivers stash non-`Send` FFI state behind it), but
+    /// [FFI_ArrowArrayStream::new] requires it. We only ever pull from it on
+    /// the thread that calls `get_next`/`release` across the FFI boundary,
+    /// so moving it there once is sound (mirrors
+    /// `driver_manager::r#async::SendReader`).
+    struct SendableReader(Box<dyn RecordBatchReader>);
+    unsafe impl Send for SendableReader {}
+
+    impl Iterator for SendableReader {
+        type Item = std::result::Result<RecordBatch, ArrowError>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.0.next()
+        }
+    }
+
+    impl RecordBatchReader for SendableReader {
+        fn schema(&self) -> arrow::datatypes::SchemaRef {
+            self.0.schema()
+        }
+    }
+}
+
+/// The signature every ADBC driver shared library exports as `AdbcDriverInit`
+/// (or a name configured via the entrypoint option).
+pub type AdbcDriverInitFunc =
+    unsafe extern "C" fn(i32, *mut c_void, *mut FFI_AdbcError) -> AdbcStatusCode;
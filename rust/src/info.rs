@@ -0,0 +1,242 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Well-known info codes and the schema used by [crate::interface::ConnectionApi::get_info].
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow::array::{
+    Array, BooleanArray, Int32Array, Int64Array, ListArray, MapArray, RecordBatchReader,
+    StringArray, UInt32Array, UnionArray,
+};
+use arrow::datatypes::{DataType, Field, Fields, Schema, UnionFields, UnionMode};
+use arrow::error::ArrowError;
+
+/// Well-known `info_code` values reserved for ADBC usage.
+///
+/// See <https://github.com/apache/arrow-adbc/blob/main/adbc.h> for the
+/// canonical list.
+pub mod codes {
+    /// The database vendor/product name.
+    pub const VENDOR_NAME: u32 = 0;
+    /// The database vendor/product version.
+    pub const VENDOR_VERSION: u32 = 1;
+    /// The database vendor/product Arrow library version.
+    pub const VENDOR_ARROW_VERSION: u32 = 2;
+    /// The driver name.
+    pub const DRIVER_NAME: u32 = 100;
+    /// The driver version.
+    pub const DRIVER_VERSION: u32 = 101;
+    /// The driver's Arrow library version.
+    pub const DRIVER_ARROW_VERSION: u32 = 102;
+    /// The ADBC API version the driver implements.
+    pub const DRIVER_ADBC_VERSION: u32 = 103;
+}
+
+/// Build the dense-union `info_value` schema, as documented on
+/// [crate::interface::ConnectionApi::get_info].
+fn info_value_union_fields() -> UnionFields {
+    UnionFields::new(
+        vec![0, 1, 2, 3, 4, 5],
+        vec![
+            Field::new("string_value", DataType::Utf8, true),
+            Field::new("bool_value", DataType::Boolean, true),
+            Field::new("int64_value", DataType::Int64, true),
+            Field::new("int32_bitmask", DataType::Int32, true),
+            Field::new(
+                "string_list",
+                DataType::List(Arc::new(Field::new("item", DataType::Utf8, true))),
+                true,
+            ),
+            Field::new(
+                "int32_to_int32_list_map",
+                DataType::Map(
+                    Arc::new(Field::new(
+                        "entries",
+                        DataType::Struct(Fields::from(vec![
+                            Field::new("keys", DataType::Int32, false),
+                            Field::new(
+                                "values",
+                                DataType::List(Arc::new(Field::new(
+                                    "item",
+                                    DataType::Int32,
+                                    true,
+                                ))),
+                                true,
+                            ),
+                        ])),
+                        false,
+                    )),
+                    false,
+                ),
+                true,
+            ),
+        ],
+    )
+}
+
+/// The Arrow schema returned by `get_info`.
+pub fn info_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("info_name", DataType::UInt32, false),
+        Field::new(
+            "info_value",
+            DataType::Union(info_value_union_fields(), UnionMode::Dense),
+            true,
+        ),
+    ])
+}
+
+/// One decoded `info_value`, mirroring the dense-union arms built by
+/// [info_value_union_fields].
+#[derive(Debug, Clone, PartialEq)]
+pub enum InfoValue {
+    StringValue(String),
+    BoolValue(bool),
+    Int64Value(i64),
+    Int32Bitmask(i32),
+    StringList(Vec<String>),
+    Int32ToInt32ListMap(HashMap<i32, Vec<i32>>),
+}
+
+/// Decode every batch `reader` yields into a `info_code` -> [InfoValue] map,
+/// as produced by [crate::interface::ConnectionApi::get_info].
+///
+/// A null `info_value` (the union's `string_value` arm and every other arm
+/// both unset) is omitted from the result rather than represented, since
+/// there is no "no value" [InfoValue] arm.
+pub fn decode_info(reader: impl RecordBatchReader) -> Result<HashMap<u32, InfoValue>, ArrowError> {
+    let mut result = HashMap::new();
+    for batch in reader {
+        let batch = batch?;
+        let info_name = batch
+            .column_by_name("info_name")
+            .and_then(|a| a.as_any().downcast_ref::<UInt32Array>())
+            .ok_or_else(|| ArrowError::SchemaError("missing info_name column".to_string()))?;
+        let info_value = batch
+            .column_by_name("info_value")
+            .and_then(|a| a.as_any().downcast_ref::<UnionArray>())
+            .ok_or_else(|| ArrowError::SchemaError("missing info_value column".to_string()))?;
+        for i in 0..batch.num_rows() {
+            if let Some(value) = decode_info_value(info_value, i) {
+                result.insert(info_name.value(i), value);
+            }
+        }
+    }
+    Ok(result)
+}
+
+fn decode_info_value(union: &UnionArray, i: usize) -> Option<InfoValue> {
+    let type_id = union.type_id(i);
+    let offset = union.value_offset(i);
+    match type_id {
+        0 => {
+            let values = union.child(type_id).as_any().downcast_ref::<StringArray>()?;
+            values
+                .is_valid(offset)
+                .then(|| InfoValue::StringValue(values.value(offset).to_string()))
+        }
+        1 => {
+            let values = union
+                .child(type_id)
+                .as_any()
+                .downcast_ref::<BooleanArray>()?;
+            values
+                .is_valid(offset)
+                .then(|| InfoValue::BoolValue(values.value(offset)))
+        }
+        2 => {
+            let values = union.child(type_id).as_any().downcast_ref::<Int64Array>()?;
+            values
+                .is_valid(offset)
+                .then(|| InfoValue::Int64Value(values.value(offset)))
+        }
+        3 => {
+            let values = union.child(type_id).as_any().downcast_ref::<Int32Array>()?;
+            values
+                .is_valid(offset)
+                .then(|| InfoValue::Int32Bitmask(values.value(offset)))
+        }
+        4 => {
+            let values = union.child(type_id).as_any().downcast_ref::<ListArray>()?;
+            if !values.is_valid(offset) {
+                return None;
+            }
+            let items = values.value(offset);
+            let items = items.as_any().downcast_ref::<StringArray>()?;
+            Some(InfoValue::StringList(
+                (0..items.len())
+                    .filter_map(|j| items.is_valid(j).then(|| items.value(j).to_string()))
+                    .collect(),
+            ))
+        }
+        5 => {
+            let values = union.child(type_id).as_any().downcast_ref::<MapArray>()?;
+            if !values.is_valid(offset) {
+                return None;
+            }
+            let entries = StructArrayView::new(values, offset);
+            Some(InfoValue::Int32ToInt32ListMap(entries.decode()))
+        }
+        _ => None,
+    }
+}
+
+/// Thin helper decoding one row of a `Map<Int32, List<Int32>>` column,
+/// since [MapArray] exposes its entries as a flat key/value pair of arrays
+/// rather than per-row structs.
+struct StructArrayView<'a> {
+    map: &'a MapArray,
+    row: usize,
+}
+
+impl<'a> StructArrayView<'a> {
+    fn new(map: &'a MapArray, row: usize) -> Self {
+        Self { map, row }
+    }
+
+    fn decode(&self) -> HashMap<i32, Vec<i32>> {
+        let entries = self.map.value(self.row);
+        let entries = entries
+            .as_any()
+            .downcast_ref::<arrow::array::StructArray>()
+            .expect("map entries are a Struct");
+        let keys = entries
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .expect("map keys are Int32");
+        let values = entries
+            .column(1)
+            .as_any()
+            .downcast_ref::<ListArray>()
+            .expect("map values are List<Int32>");
+        (0..entries.num_rows())
+            .filter_map(|i| {
+                if !keys.is_valid(i) || !values.is_valid(i) {
+                    return None;
+                }
+                let list = values.value(i);
+                let list = list.as_any().downcast_ref::<Int32Array>()?;
+                let items = (0..list.len())
+                    .filter_map(|j| list.is_valid(j).then(|| list.value(j)))
+                    .collect();
+                Some((keys.value(i), items))
+            })
+            .collect()
+    }
+}
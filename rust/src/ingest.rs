@@ -0,0 +1,321 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Versioned, change-data-capture style ingestion, for
+//! [crate::interface::StatementApi::bind_change_stream].
+use std::collections::HashMap;
+
+use arrow::array::UInt32Array;
+use arrow::compute::{concat_batches, take};
+use arrow::error::ArrowError;
+use arrow::record_batch::RecordBatch;
+use arrow::row::{RowConverter, SortField};
+
+/// The kind of change a [ChangeBatch] applies to the target table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChangeOperation {
+    Insert,
+    Update,
+    Delete,
+}
+
+/// One batch of a versioned change-data stream.
+///
+/// `version` must increase monotonically across the batches fed to a single
+/// [crate::interface::StatementApi::bind_change_stream] call; it is what
+/// lets [coalesce_changes] determine which of several deltas for the same
+/// primary key "wins".
+pub struct ChangeBatch {
+    pub version: u64,
+    pub operation: ChangeOperation,
+    pub batch: RecordBatch,
+}
+
+/// A stream of [ChangeBatch]es, fed to
+/// [crate::interface::StatementApi::bind_change_stream].
+pub type ChangeStream = Box<dyn Iterator<Item = ChangeBatch>>;
+
+/// Coalesce a flush's worth of [ChangeBatch]es down to the latest operation
+/// per primary key (as identified by `key_columns`), so that e.g. an insert
+/// immediately followed by a delete of the same row cancel out instead of
+/// being applied as two separate driver round-trips.
+///
+/// A key whose earliest version in `changes` is an [ChangeOperation::Insert]
+/// and whose latest is a [ChangeOperation::Delete] is dropped entirely
+/// rather than surviving as a `Delete`: since this flush never applied the
+/// insert to the target table, there is nothing there left to delete.
+/// A `Delete` of a key with no `Insert` in this flush is assumed to target a
+/// row that already existed in the table, so it is kept and still surfaces
+/// through the normal `Delete` bucket below.
+///
+/// Likewise, a key whose earliest version is an `Insert` but whose winning
+/// (latest) version is an [ChangeOperation::Update] is surfaced as an
+/// `Insert` of the winning row, not an `Update`: the row was never applied
+/// to the target table this flush, so there is nothing there yet for an
+/// `Update` to modify.
+///
+/// Returns at most one [ChangeBatch] per [ChangeOperation] present in the
+/// output, in `Delete`, `Insert`, `Update` order (deletes are applied
+/// first, so a row that is deleted and then re-inserted in the same flush
+/// ends up present).
+///
+/// An empty `key_columns` means "no primary key to dedup on", not "every
+/// row shares one key" -- `changes` is returned unchanged rather than
+/// collapsing every row of a given [ChangeOperation] down to whichever one
+/// happened to be seen last.
+pub fn coalesce_changes(
+    changes: Vec<ChangeBatch>,
+    key_columns: &[&str],
+) -> Result<Vec<ChangeBatch>, ArrowError> {
+    if key_columns.is_empty() {
+        return Ok(changes);
+    }
+    let Some(first) = changes.first() else {
+        return Ok(Vec::new());
+    };
+    let schema = first.batch.schema();
+
+    let mut row_meta = Vec::new();
+    for change in &changes {
+        for _ in 0..change.batch.num_rows() {
+            row_meta.push((change.version, change.operation));
+        }
+    }
+    let batches: Vec<&RecordBatch> = changes.iter().map(|c| &c.batch).collect();
+    let combined = concat_batches(&schema, batches)?;
+
+    let key_indices: Vec<usize> = key_columns
+        .iter()
+        .map(|name| schema.index_of(name))
+        .collect::<Result<_, _>>()?;
+    let fields: Vec<SortField> = key_indices
+        .iter()
+        .map(|&i| SortField::new(schema.field(i).data_type().clone()))
+        .collect();
+    let converter = RowConverter::new(fields)?;
+    let key_columns: Vec<_> = key_indices.iter().map(|&i| combined.column(i).clone()).collect();
+    let rows = converter.convert_columns(&key_columns)?;
+
+    // For each primary key, keep only the row with the highest `version`,
+    // and remember which operation the key's lowest `version` arrived as
+    // (`changes` is fed in increasing version order, so that's whichever
+    // operation we see first for a given key).
+    let mut winner_for_key: HashMap<Vec<u8>, usize> = HashMap::new();
+    let mut first_op_for_key: HashMap<Vec<u8>, ChangeOperation> = HashMap::new();
+    let mut first_seen_order: Vec<Vec<u8>> = Vec::new();
+    for row_idx in 0..combined.num_rows() {
+        let key = rows.row(row_idx).as_ref().to_vec();
+        match winner_for_key.get(&key) {
+            Some(&existing) if row_meta[existing].0 >= row_meta[row_idx].0 => {}
+            _ => {
+                if !winner_for_key.contains_key(&key) {
+                    first_seen_order.push(key.clone());
+                    first_op_for_key.insert(key.clone(), row_meta[row_idx].1);
+                }
+                winner_for_key.insert(key, row_idx);
+            }
+        }
+    }
+
+    let mut indices_by_op: HashMap<ChangeOperation, Vec<u32>> = HashMap::new();
+    for key in &first_seen_order {
+        let row_idx = winner_for_key[key];
+        let mut operation = row_meta[row_idx].1;
+        // An insert that this same flush goes on to delete never actually
+        // reached the target table, so it cancels out rather than becoming
+        // a `Delete` of a row that was never there.
+        if operation == ChangeOperation::Delete && first_op_for_key[key] == ChangeOperation::Insert
+        {
+            continue;
+        }
+        // Likewise, an insert that this same flush goes on to update never
+        // actually reached the target table, so the winning row must still
+        // be applied as an `Insert` rather than an `Update` of a row that
+        // isn't there yet.
+        if operation == ChangeOperation::Update && first_op_for_key[key] == ChangeOperation::Insert
+        {
+            operation = ChangeOperation::Insert;
+        }
+        indices_by_op.entry(operation).or_default().push(row_idx as u32);
+    }
+
+    let mut output = Vec::new();
+    for operation in [
+        ChangeOperation::Delete,
+        ChangeOperation::Insert,
+        ChangeOperation::Update,
+    ] {
+        let Some(indices) = indices_by_op.remove(&operation) else {
+            continue;
+        };
+        let index_array = UInt32Array::from(indices);
+        let columns = combined
+            .columns()
+            .iter()
+            .map(|c| take(c, &index_array, None))
+            .collect::<Result<Vec<_>, _>>()?;
+        let batch = RecordBatch::try_new(schema.clone(), columns)?;
+        // The per-row version no longer has a single meaningful value once
+        // rows from different flush versions are combined into one batch.
+        output.push(ChangeBatch {
+            version: 0,
+            operation,
+            batch,
+        });
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Array, Int64Array, StringArray};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use std::sync::Arc;
+
+    fn batch(ids: &[i64], values: &[&str]) -> RecordBatch {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("value", DataType::Utf8, false),
+        ]));
+        RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int64Array::from(ids.to_vec())),
+                Arc::new(StringArray::from(values.to_vec())),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_coalesce_keeps_latest_version_per_key() {
+        let changes = vec![
+            ChangeBatch {
+                version: 1,
+                operation: ChangeOperation::Insert,
+                batch: batch(&[1, 2], &["a", "b"]),
+            },
+            ChangeBatch {
+                version: 2,
+                operation: ChangeOperation::Update,
+                batch: batch(&[1], &["a-updated"]),
+            },
+        ];
+
+        let coalesced = coalesce_changes(changes, &["id"]).unwrap();
+
+        // Row 1 was inserted and then updated within the same flush, so it
+        // never reached the target table as the original "a" row; both the
+        // untouched row 2 and the winning version of row 1 surface as a
+        // single `Insert` bucket, carrying the winning value for row 1.
+        assert_eq!(coalesced.len(), 1);
+        assert_eq!(coalesced[0].operation, ChangeOperation::Insert);
+        assert_eq!(coalesced[0].batch.num_rows(), 2);
+        let values = coalesced[0]
+            .batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(values.value(0), "a-updated");
+        assert_eq!(values.value(1), "b");
+    }
+
+    #[test]
+    fn test_coalesce_keeps_update_for_key_not_inserted_this_flush() {
+        let changes = vec![ChangeBatch {
+            version: 1,
+            operation: ChangeOperation::Update,
+            batch: batch(&[1], &["a-updated"]),
+        }];
+
+        // An update with no prior insert in this flush is assumed to target
+        // a row that already exists in the table, so it must still surface
+        // as an `Update` rather than being relabeled as an `Insert`.
+        let coalesced = coalesce_changes(changes, &["id"]).unwrap();
+        assert_eq!(coalesced.len(), 1);
+        assert_eq!(coalesced[0].operation, ChangeOperation::Update);
+        assert_eq!(coalesced[0].batch.num_rows(), 1);
+    }
+
+    #[test]
+    fn test_coalesce_cancels_out_insert_then_delete_of_same_key() {
+        let changes = vec![
+            ChangeBatch {
+                version: 1,
+                operation: ChangeOperation::Insert,
+                batch: batch(&[1, 2], &["a", "b"]),
+            },
+            ChangeBatch {
+                version: 2,
+                operation: ChangeOperation::Delete,
+                batch: batch(&[1], &["a"]),
+            },
+        ];
+
+        let coalesced = coalesce_changes(changes, &["id"]).unwrap();
+
+        // Row 1 was inserted and deleted within the same flush, so it never
+        // reached the target table and should vanish entirely rather than
+        // surviving as a `Delete`; row 2 is untouched and still inserted.
+        assert_eq!(coalesced.len(), 1);
+        assert_eq!(coalesced[0].operation, ChangeOperation::Insert);
+        assert_eq!(coalesced[0].batch.num_rows(), 1);
+        let ids = coalesced[0]
+            .batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert_eq!(ids.value(0), 2);
+    }
+
+    #[test]
+    fn test_coalesce_keeps_delete_with_no_insert_in_this_flush() {
+        let changes = vec![ChangeBatch {
+            version: 1,
+            operation: ChangeOperation::Delete,
+            batch: batch(&[1], &["a"]),
+        }];
+
+        // A delete with no prior insert in this flush is assumed to target
+        // a row that already existed in the table, so it must still
+        // surface rather than being silently dropped.
+        let coalesced = coalesce_changes(changes, &["id"]).unwrap();
+        assert_eq!(coalesced.len(), 1);
+        assert_eq!(coalesced[0].operation, ChangeOperation::Delete);
+        assert_eq!(coalesced[0].batch.num_rows(), 1);
+    }
+
+    #[test]
+    fn test_coalesce_with_no_key_columns_passes_every_row_through() {
+        let changes = vec![
+            ChangeBatch {
+                version: 1,
+                operation: ChangeOperation::Insert,
+                batch: batch(&[1, 1, 1], &["a", "b", "c"]),
+            },
+        ];
+
+        // With no key columns to dedup on, every row must survive rather
+        // than all rows of the batch collapsing onto one "key".
+        let coalesced = coalesce_changes(changes, &[]).unwrap();
+        assert_eq!(coalesced.len(), 1);
+        assert_eq!(coalesced[0].batch.num_rows(), 3);
+    }
+}
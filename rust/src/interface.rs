@@ -22,6 +22,7 @@
 use arrow::{datatypes::Schema, record_batch::RecordBatch, record_batch::RecordBatchReader};
 
 use crate::ffi::AdbcObjectDepth;
+use crate::ingest::ChangeStream;
 
 /// Databases hold state shared by multiple connections. This typically means
 /// configuration and caches. For in-memory databases, it provides a place to
@@ -296,6 +297,26 @@ pub trait StatementApi {
     ///
     /// See [PartitionedStatementResult].
     fn execute_partitioned(&mut self) -> Result<PartitionedStatementResult, Self::Error>;
+
+    /// Bind a versioned, change-data-capture style stream of row batches,
+    /// each tagged with a [crate::ingest::ChangeOperation] and a
+    /// monotonically increasing version number, for use with
+    /// [StatementApi::execute_ingest].
+    ///
+    /// Implementations should buffer the stream (e.g. coalescing it with
+    /// [crate::ingest::coalesce_changes]) rather than applying each batch
+    /// immediately, since later batches in the same flush may overwrite or
+    /// cancel out earlier ones for the same primary key.
+    fn bind_change_stream(&mut self, stream: ChangeStream) -> Result<(), Self::Error>;
+
+    /// Apply a previously-bound change stream (see
+    /// [StatementApi::bind_change_stream]) to `target_table`, in version
+    /// order, coalescing consecutive deltas for the same primary key within
+    /// one flush (e.g. an insert immediately followed by a delete of the
+    /// same row cancels out).
+    ///
+    /// Returns the number of rows affected, or -1 if unknown or unsupported.
+    fn execute_ingest(&mut self, target_table: &str) -> Result<i64, Self::Error>;
 }
 
 /// Result of calling [StatementApi::execute].
@@ -321,4 +342,48 @@ pub struct PartitionedStatementResult {
     pub schema: Schema,
     pub partition_ids: Vec<Vec<u8>>,
     pub rows_affected: i64,
-}
\ No newline at end of file
+}
+
+/// Async-native sibling of [ConnectionApi], for driver implementations that
+/// are themselves backed by an async client (e.g. a Tokio-based network
+/// driver) and so can serve metadata queries without a blocking thread hop.
+///
+/// This only covers [ConnectionApi::get_objects]; the other [ConnectionApi]
+/// methods, including the option setters, have no async counterpart here.
+/// Gated behind the `tokio` feature, matching
+/// [crate::driver_manager::r#async].
+#[cfg(feature = "tokio")]
+#[async_trait::async_trait]
+pub trait AsyncConnectionApi {
+    type Error;
+
+    /// Async equivalent of [ConnectionApi::get_objects].
+    async fn get_objects(
+        &self,
+        depth: AdbcObjectDepth,
+        catalog: Option<&str>,
+        db_schema: Option<&str>,
+        table_name: Option<&str>,
+        table_type: Option<&[&str]>,
+        column_name: Option<&str>,
+    ) -> Result<Box<dyn RecordBatchReader>, Self::Error>;
+}
+
+/// Async-native sibling of [StatementApi], for driver implementations that
+/// are themselves backed by an async client.
+///
+/// This only covers [StatementApi::execute] and
+/// [StatementApi::execute_update]; the other [StatementApi] methods have no
+/// async counterpart here. Gated behind the `tokio` feature, matching
+/// [crate::driver_manager::r#async].
+#[cfg(feature = "tokio")]
+#[async_trait::async_trait]
+pub trait AsyncStatementApi {
+    type Error;
+
+    /// Async equivalent of [StatementApi::execute].
+    async fn execute(&mut self) -> Result<StatementResult, Self::Error>;
+
+    /// Async equivalent of [StatementApi::execute_update].
+    async fn execute_update(&mut self) -> Result<i64, Self::Error>;
+}
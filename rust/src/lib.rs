@@ -0,0 +1,42 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A Rust implementation of [ADBC](https://arrow.apache.org/adbc/), both for
+//! consuming ADBC drivers ([driver_manager]) and for implementing them
+//! ([implement]).
+
+pub mod conformance;
+#[cfg(feature = "datafusion")]
+pub mod datafusion;
+pub mod driver_manager;
+pub mod error;
+pub mod ffi;
+#[cfg(feature = "flight-sql")]
+pub mod flight_sql;
+pub mod implement;
+pub mod info;
+pub mod ingest;
+pub mod interface;
+pub mod objects;
+pub mod options;
+#[cfg(feature = "substrait")]
+pub mod substrait;
+
+/// ADBC API version 1.0.0.
+pub const ADBC_VERSION_1_0_0: i32 = 1_000_000;
+/// ADBC API version 1.1.0.
+pub const ADBC_VERSION_1_1_0: i32 = 1_001_000;
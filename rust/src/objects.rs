@@ -0,0 +1,909 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Canonical schema and row-based builder for
+//! [crate::interface::ConnectionApi::get_objects].
+//!
+//! A driver collects its catalog/schema/table/column metadata into the
+//! plain [CatalogInfo] row types below, unfiltered, and hands it to
+//! [get_objects_batch] along with the `depth`/filter arguments it was
+//! called with; this module takes care of applying the SQL-LIKE filters,
+//! truncating the nesting to `depth`, and all of the
+//! [StructBuilder]/[ListBuilder] marshalling into the nested Arrow
+//! `RecordBatch` the ADBC spec requires.
+//!
+//! This models the core `GetObjects` schema (catalog, db_schema, table,
+//! column, constraint, constraint usage) documented on
+//! [crate::interface::ConnectionApi::get_objects]. It does not build the
+//! optional JDBC/ODBC-compatible `xdbc_*` column metadata; a driver that
+//! wants to report those can build on top of [get_objects_schema] itself.
+use std::sync::Arc;
+
+use arrow::array::{
+    Array, ArrayRef, BooleanArray, Int16Array, Int32Array, Int32Builder, ListArray, ListBuilder,
+    RecordBatch, RecordBatchReader, StringArray, StringBuilder, StructArray, StructBuilder,
+};
+use arrow::datatypes::{DataType, Field, Fields, Schema};
+use arrow::error::ArrowError;
+
+use crate::ffi::AdbcObjectDepth;
+
+/// One column of a [TableInfo], matching `COLUMN_SCHEMA`.
+#[derive(Debug, Clone, Default)]
+pub struct ColumnInfo {
+    pub column_name: String,
+    pub ordinal_position: Option<i32>,
+    pub remarks: Option<String>,
+}
+
+/// One entry of a [ConstraintInfo]'s `constraint_column_usage`, matching
+/// `USAGE_SCHEMA`.
+#[derive(Debug, Clone, Default)]
+pub struct ConstraintUsage {
+    pub fk_catalog: Option<String>,
+    pub fk_db_schema: Option<String>,
+    pub fk_table: String,
+    pub fk_column_name: String,
+}
+
+/// One constraint on a [TableInfo], matching `CONSTRAINT_SCHEMA`.
+#[derive(Debug, Clone, Default)]
+pub struct ConstraintInfo {
+    pub constraint_name: Option<String>,
+    pub constraint_type: String,
+    pub column_names: Vec<String>,
+    pub usage: Vec<ConstraintUsage>,
+}
+
+/// One table or view, matching `TABLE_SCHEMA`.
+#[derive(Debug, Clone, Default)]
+pub struct TableInfo {
+    pub table_name: String,
+    pub table_type: String,
+    pub columns: Vec<ColumnInfo>,
+    pub constraints: Vec<ConstraintInfo>,
+}
+
+/// One database schema, matching `DB_SCHEMA_SCHEMA`.
+#[derive(Debug, Clone, Default)]
+pub struct DbSchemaInfo {
+    pub db_schema_name: Option<String>,
+    pub tables: Vec<TableInfo>,
+}
+
+/// One catalog, matching the top-level row of `GetObjects`.
+#[derive(Debug, Clone, Default)]
+pub struct CatalogInfo {
+    pub catalog_name: Option<String>,
+    pub db_schemas: Vec<DbSchemaInfo>,
+}
+
+/// Whether `value` matches the SQL-LIKE `pattern` (`%` matches any run of
+/// characters, `_` matches exactly one). A `None` pattern matches
+/// anything; a `None` value only matches a `None` or empty pattern.
+fn like_matches(pattern: Option<&str>, value: Option<&str>) -> bool {
+    let Some(pattern) = pattern else {
+        return true;
+    };
+    let Some(value) = value else {
+        return pattern.is_empty();
+    };
+
+    fn matches(pattern: &[char], value: &[char]) -> bool {
+        match pattern.first() {
+            None => value.is_empty(),
+            Some('%') => {
+                matches(&pattern[1..], value)
+                    || (!value.is_empty() && matches(pattern, &value[1..]))
+            }
+            Some('_') => !value.is_empty() && matches(&pattern[1..], &value[1..]),
+            Some(c) => value.first() == Some(c) && matches(&pattern[1..], &value[1..]),
+        }
+    }
+
+    let pattern: Vec<char> = pattern.chars().collect();
+    let value: Vec<char> = value.chars().collect();
+    matches(&pattern, &value)
+}
+
+fn usage_fields() -> Fields {
+    Fields::from(vec![
+        Field::new("fk_catalog", DataType::Utf8, true),
+        Field::new("fk_db_schema", DataType::Utf8, true),
+        Field::new("fk_table", DataType::Utf8, false),
+        Field::new("fk_column_name", DataType::Utf8, false),
+    ])
+}
+
+fn constraint_fields() -> Fields {
+    Fields::from(vec![
+        Field::new("constraint_name", DataType::Utf8, true),
+        Field::new("constraint_type", DataType::Utf8, false),
+        Field::new(
+            "constraint_column_names",
+            DataType::List(Arc::new(Field::new("item", DataType::Utf8, true))),
+            false,
+        ),
+        Field::new(
+            "constraint_column_usage",
+            DataType::List(Arc::new(Field::new(
+                "item",
+                DataType::Struct(usage_fields()),
+                true,
+            ))),
+            true,
+        ),
+    ])
+}
+
+fn column_fields() -> Fields {
+    Fields::from(vec![
+        Field::new("column_name", DataType::Utf8, false),
+        Field::new("ordinal_position", DataType::Int32, true),
+        Field::new("remarks", DataType::Utf8, true),
+    ])
+}
+
+fn table_fields() -> Fields {
+    Fields::from(vec![
+        Field::new("table_name", DataType::Utf8, false),
+        Field::new("table_type", DataType::Utf8, false),
+        Field::new(
+            "table_columns",
+            DataType::List(Arc::new(Field::new(
+                "item",
+                DataType::Struct(column_fields()),
+                true,
+            ))),
+            true,
+        ),
+        Field::new(
+            "table_constraints",
+            DataType::List(Arc::new(Field::new(
+                "item",
+                DataType::Struct(constraint_fields()),
+                true,
+            ))),
+            true,
+        ),
+    ])
+}
+
+fn db_schema_fields() -> Fields {
+    Fields::from(vec![
+        Field::new("db_schema_name", DataType::Utf8, true),
+        Field::new(
+            "db_schema_tables",
+            DataType::List(Arc::new(Field::new(
+                "item",
+                DataType::Struct(table_fields()),
+                true,
+            ))),
+            true,
+        ),
+    ])
+}
+
+/// The Arrow schema returned by `get_objects`, regardless of `depth`:
+/// `depth` only controls whether the nested list fields below the
+/// requested level are populated or left null, not the schema's shape.
+pub fn get_objects_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("catalog_name", DataType::Utf8, true),
+        Field::new(
+            "catalog_db_schemas",
+            DataType::List(Arc::new(Field::new(
+                "item",
+                DataType::Struct(db_schema_fields()),
+                true,
+            ))),
+            true,
+        ),
+    ])
+}
+
+fn new_usage_builder() -> StructBuilder {
+    StructBuilder::new(
+        usage_fields(),
+        vec![
+            Box::new(StringBuilder::new()),
+            Box::new(StringBuilder::new()),
+            Box::new(StringBuilder::new()),
+            Box::new(StringBuilder::new()),
+        ],
+    )
+}
+
+fn new_constraint_builder() -> StructBuilder {
+    StructBuilder::new(
+        constraint_fields(),
+        vec![
+            Box::new(StringBuilder::new()),
+            Box::new(StringBuilder::new()),
+            Box::new(ListBuilder::new(StringBuilder::new())),
+            Box::new(ListBuilder::new(new_usage_builder())),
+        ],
+    )
+}
+
+fn new_column_builder() -> StructBuilder {
+    StructBuilder::new(
+        column_fields(),
+        vec![
+            Box::new(StringBuilder::new()),
+            Box::new(Int32Builder::new()),
+            Box::new(StringBuilder::new()),
+        ],
+    )
+}
+
+fn new_table_builder() -> StructBuilder {
+    StructBuilder::new(
+        table_fields(),
+        vec![
+            Box::new(StringBuilder::new()),
+            Box::new(StringBuilder::new()),
+            Box::new(ListBuilder::new(new_column_builder())),
+            Box::new(ListBuilder::new(new_constraint_builder())),
+        ],
+    )
+}
+
+fn new_db_schema_builder() -> StructBuilder {
+    StructBuilder::new(
+        db_schema_fields(),
+        vec![
+            Box::new(StringBuilder::new()),
+            Box::new(ListBuilder::new(new_table_builder())),
+        ],
+    )
+}
+
+fn append_usage(builder: &mut StructBuilder, usage: &ConstraintUsage) {
+    builder
+        .field_builder::<StringBuilder>(0)
+        .unwrap()
+        .append_option(usage.fk_catalog.as_deref());
+    builder
+        .field_builder::<StringBuilder>(1)
+        .unwrap()
+        .append_option(usage.fk_db_schema.as_deref());
+    builder
+        .field_builder::<StringBuilder>(2)
+        .unwrap()
+        .append_value(&usage.fk_table);
+    builder
+        .field_builder::<StringBuilder>(3)
+        .unwrap()
+        .append_value(&usage.fk_column_name);
+    builder.append(true);
+}
+
+fn append_constraint(builder: &mut StructBuilder, constraint: &ConstraintInfo) {
+    builder
+        .field_builder::<StringBuilder>(0)
+        .unwrap()
+        .append_option(constraint.constraint_name.as_deref());
+    builder
+        .field_builder::<StringBuilder>(1)
+        .unwrap()
+        .append_value(&constraint.constraint_type);
+    {
+        let names = builder
+            .field_builder::<ListBuilder<StringBuilder>>(2)
+            .unwrap();
+        for name in &constraint.column_names {
+            names.values().append_value(name);
+        }
+        names.append(true);
+    }
+    {
+        let usages = builder
+            .field_builder::<ListBuilder<StructBuilder>>(3)
+            .unwrap();
+        for usage in &constraint.usage {
+            append_usage(usages.values(), usage);
+        }
+        usages.append(true);
+    }
+    builder.append(true);
+}
+
+fn append_column(builder: &mut StructBuilder, column: &ColumnInfo, column_name: Option<&str>) {
+    if !like_matches(column_name, Some(&column.column_name)) {
+        return;
+    }
+    builder
+        .field_builder::<StringBuilder>(0)
+        .unwrap()
+        .append_value(&column.column_name);
+    builder
+        .field_builder::<Int32Builder>(1)
+        .unwrap()
+        .append_option(column.ordinal_position);
+    builder
+        .field_builder::<StringBuilder>(2)
+        .unwrap()
+        .append_option(column.remarks.as_deref());
+    builder.append(true);
+}
+
+fn append_table(
+    builder: &mut StructBuilder,
+    table: &TableInfo,
+    depth: AdbcObjectDepth,
+    column_name: Option<&str>,
+) {
+    builder
+        .field_builder::<StringBuilder>(0)
+        .unwrap()
+        .append_value(&table.table_name);
+    builder
+        .field_builder::<StringBuilder>(1)
+        .unwrap()
+        .append_value(&table.table_type);
+    {
+        let columns = builder
+            .field_builder::<ListBuilder<StructBuilder>>(2)
+            .unwrap();
+        if depth == AdbcObjectDepth::All {
+            for column in &table.columns {
+                append_column(columns.values(), column, column_name);
+            }
+            columns.append(true);
+        } else {
+            columns.append(false);
+        }
+    }
+    {
+        let constraints = builder
+            .field_builder::<ListBuilder<StructBuilder>>(3)
+            .unwrap();
+        if depth == AdbcObjectDepth::All {
+            for constraint in &table.constraints {
+                append_constraint(constraints.values(), constraint);
+            }
+            constraints.append(true);
+        } else {
+            constraints.append(false);
+        }
+    }
+    builder.append(true);
+}
+
+fn append_db_schema(
+    builder: &mut StructBuilder,
+    db_schema: &DbSchemaInfo,
+    depth: AdbcObjectDepth,
+    table_name: Option<&str>,
+    table_type: Option<&[&str]>,
+    column_name: Option<&str>,
+) {
+    builder
+        .field_builder::<StringBuilder>(0)
+        .unwrap()
+        .append_option(db_schema.db_schema_name.as_deref());
+    let tables = builder
+        .field_builder::<ListBuilder<StructBuilder>>(1)
+        .unwrap();
+    if depth == AdbcObjectDepth::Tables || depth == AdbcObjectDepth::All {
+        for table in db_schema.tables.iter().filter(|table| {
+            like_matches(table_name, Some(&table.table_name))
+                && table_type.map_or(true, |types| types.contains(&table.table_type.as_str()))
+        }) {
+            append_table(tables.values(), table, depth, column_name);
+        }
+        tables.append(true);
+    } else {
+        tables.append(false);
+    }
+    builder.append(true);
+}
+
+fn append_catalog(
+    catalog_name: &mut StringBuilder,
+    db_schemas: &mut ListBuilder<StructBuilder>,
+    catalog: &CatalogInfo,
+    depth: AdbcObjectDepth,
+    db_schema_filter: Option<&str>,
+    table_name: Option<&str>,
+    table_type: Option<&[&str]>,
+    column_name: Option<&str>,
+) {
+    catalog_name.append_option(catalog.catalog_name.as_deref());
+    if depth == AdbcObjectDepth::DBSchemas
+        || depth == AdbcObjectDepth::Tables
+        || depth == AdbcObjectDepth::All
+    {
+        for db_schema in catalog
+            .db_schemas
+            .iter()
+            .filter(|schema| like_matches(db_schema_filter, schema.db_schema_name.as_deref()))
+        {
+            append_db_schema(
+                db_schemas.values(),
+                db_schema,
+                depth,
+                table_name,
+                table_type,
+                column_name,
+            );
+        }
+        db_schemas.append(true);
+    } else {
+        db_schemas.append(false);
+    }
+}
+
+/// Build the `RecordBatch` [crate::interface::ConnectionApi::get_objects]
+/// returns, applying the standard SQL-LIKE filters and truncating the
+/// nesting to `depth` along the way.
+///
+/// `catalogs` need not be pre-filtered or pre-truncated by the caller --
+/// this is the one place that logic lives, so every driver gets it for
+/// free just by yielding its full catalog/schema/table/column metadata.
+pub fn get_objects_batch(
+    depth: AdbcObjectDepth,
+    catalog: Option<&str>,
+    db_schema: Option<&str>,
+    table_name: Option<&str>,
+    table_type: Option<&[&str]>,
+    column_name: Option<&str>,
+    catalogs: &[CatalogInfo],
+) -> std::result::Result<RecordBatch, ArrowError> {
+    let mut catalog_name = StringBuilder::new();
+    let mut db_schemas = ListBuilder::new(new_db_schema_builder());
+    for cat in catalogs
+        .iter()
+        .filter(|cat| like_matches(catalog, cat.catalog_name.as_deref()))
+    {
+        append_catalog(
+            &mut catalog_name,
+            &mut db_schemas,
+            cat,
+            depth,
+            db_schema,
+            table_name,
+            table_type,
+            column_name,
+        );
+    }
+
+    let catalog_name: ArrayRef = Arc::new(catalog_name.finish());
+    let catalog_db_schemas: ArrayRef = Arc::new(db_schemas.finish());
+    RecordBatch::try_new(
+        Arc::new(get_objects_schema()),
+        vec![catalog_name, catalog_db_schemas],
+    )
+}
+
+// ---------------------------------------------------------------------
+// Reader side: decoding a `get_objects` `RecordBatchReader` back into
+// plain Rust structs, the reverse of [get_objects_batch] above.
+// ---------------------------------------------------------------------
+
+/// One decoded column, the reader-side counterpart of [ColumnInfo].
+///
+/// The `xdbc_*` fields are JDBC/ODBC-compatible metadata that
+/// [get_objects_batch] never produces (see the module docs), but a
+/// third-party driver's output may include them, so they're decoded here
+/// as `None` when the corresponding field is absent from the schema
+/// rather than treated as an error.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Column {
+    pub column_name: String,
+    pub ordinal_position: Option<i32>,
+    pub remarks: Option<String>,
+    pub xdbc_type_name: Option<String>,
+    pub xdbc_column_size: Option<i32>,
+    pub xdbc_decimal_digits: Option<i16>,
+    pub xdbc_num_prec_radix: Option<i16>,
+    pub xdbc_nullable: Option<i16>,
+    pub xdbc_column_def: Option<String>,
+    pub xdbc_sql_data_type: Option<i16>,
+    pub xdbc_datetime_sub: Option<i16>,
+    pub xdbc_char_octet_length: Option<i32>,
+    pub xdbc_is_nullable: Option<String>,
+    pub xdbc_is_autoincrement: Option<bool>,
+    pub xdbc_is_generatedcolumn: Option<bool>,
+}
+
+/// One decoded constraint, the reader-side counterpart of [ConstraintInfo].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Constraint {
+    pub constraint_name: Option<String>,
+    pub constraint_type: String,
+    pub column_names: Vec<String>,
+    pub column_usage: Vec<ConstraintUsage>,
+}
+
+/// One decoded table, the reader-side counterpart of [TableInfo].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Table {
+    pub table_name: String,
+    pub table_type: String,
+    pub columns: Vec<Column>,
+    pub constraints: Vec<Constraint>,
+}
+
+/// One decoded database schema, the reader-side counterpart of
+/// [DbSchemaInfo].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DbSchema {
+    pub db_schema_name: Option<String>,
+    pub tables: Vec<Table>,
+}
+
+/// One decoded catalog, the reader-side counterpart of [CatalogInfo].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Catalog {
+    pub catalog_name: Option<String>,
+    pub schemas: Vec<DbSchema>,
+}
+
+/// A fully-decoded `get_objects` result.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CatalogCollection {
+    pub catalogs: Vec<Catalog>,
+}
+
+impl CatalogCollection {
+    /// Decode every batch `reader` yields into [Catalog] rows.
+    ///
+    /// Tolerant of truncated `depth`: a null `catalog_db_schemas` or
+    /// `db_schema_tables` (as produced when `depth` was
+    /// [AdbcObjectDepth::Catalogs] or [AdbcObjectDepth::DBSchemas]) decodes
+    /// to an empty `Vec` rather than an error, and likewise for null
+    /// `table_columns`/`table_constraints` at [AdbcObjectDepth::Tables].
+    pub fn try_from_reader(
+        reader: impl RecordBatchReader,
+    ) -> std::result::Result<Self, ArrowError> {
+        let mut catalogs = Vec::new();
+        for batch in reader {
+            catalogs.extend(decode_catalogs(&batch?));
+        }
+        Ok(Self { catalogs })
+    }
+}
+
+fn as_string_array(array: Option<&ArrayRef>) -> Option<&StringArray> {
+    array.and_then(|a| a.as_any().downcast_ref::<StringArray>())
+}
+
+fn as_list_array(array: Option<&ArrayRef>) -> Option<&ListArray> {
+    array.and_then(|a| a.as_any().downcast_ref::<ListArray>())
+}
+
+fn opt_string(array: Option<&StringArray>, i: usize) -> Option<String> {
+    array
+        .filter(|a| a.is_valid(i))
+        .map(|a| a.value(i).to_string())
+}
+
+fn opt_i16(array: Option<&Int16Array>, i: usize) -> Option<i16> {
+    array.filter(|a| a.is_valid(i)).map(|a| a.value(i))
+}
+
+fn opt_i32(array: Option<&Int32Array>, i: usize) -> Option<i32> {
+    array.filter(|a| a.is_valid(i)).map(|a| a.value(i))
+}
+
+fn opt_bool(array: Option<&BooleanArray>, i: usize) -> Option<bool> {
+    array.filter(|a| a.is_valid(i)).map(|a| a.value(i))
+}
+
+/// The nested struct array for row `i` of `list`, or `None` if that row is
+/// null (a truncated-depth list field).
+fn nested_struct(list: &ListArray, i: usize) -> Option<StructArray> {
+    list.is_valid(i)
+        .then(|| StructArray::from(list.value(i).to_data()))
+}
+
+fn decode_catalogs(batch: &RecordBatch) -> Vec<Catalog> {
+    let catalog_name = as_string_array(batch.column_by_name("catalog_name"));
+    let catalog_db_schemas = as_list_array(batch.column_by_name("catalog_db_schemas"));
+    (0..batch.num_rows())
+        .map(|i| Catalog {
+            catalog_name: opt_string(catalog_name, i),
+            schemas: catalog_db_schemas
+                .and_then(|list| nested_struct(list, i))
+                .map(|schemas| decode_db_schemas(&schemas))
+                .unwrap_or_default(),
+        })
+        .collect()
+}
+
+fn decode_db_schemas(array: &StructArray) -> Vec<DbSchema> {
+    let db_schema_name = array
+        .column_by_name("db_schema_name")
+        .and_then(|a| a.as_any().downcast_ref::<StringArray>());
+    let db_schema_tables = array
+        .column_by_name("db_schema_tables")
+        .and_then(|a| a.as_any().downcast_ref::<ListArray>());
+    (0..array.len())
+        .map(|i| DbSchema {
+            db_schema_name: opt_string(db_schema_name, i),
+            tables: db_schema_tables
+                .and_then(|list| nested_struct(list, i))
+                .map(|tables| decode_tables(&tables))
+                .unwrap_or_default(),
+        })
+        .collect()
+}
+
+fn decode_tables(array: &StructArray) -> Vec<Table> {
+    let table_name = array
+        .column_by_name("table_name")
+        .and_then(|a| a.as_any().downcast_ref::<StringArray>());
+    let table_type = array
+        .column_by_name("table_type")
+        .and_then(|a| a.as_any().downcast_ref::<StringArray>());
+    let table_columns = array
+        .column_by_name("table_columns")
+        .and_then(|a| a.as_any().downcast_ref::<ListArray>());
+    let table_constraints = array
+        .column_by_name("table_constraints")
+        .and_then(|a| a.as_any().downcast_ref::<ListArray>());
+    (0..array.len())
+        .map(|i| Table {
+            table_name: opt_string(table_name, i).unwrap_or_default(),
+            table_type: opt_string(table_type, i).unwrap_or_default(),
+            columns: table_columns
+                .and_then(|list| nested_struct(list, i))
+                .map(|columns| decode_columns(&columns))
+                .unwrap_or_default(),
+            constraints: table_constraints
+                .and_then(|list| nested_struct(list, i))
+                .map(|constraints| decode_constraints(&constraints))
+                .unwrap_or_default(),
+        })
+        .collect()
+}
+
+fn decode_columns(array: &StructArray) -> Vec<Column> {
+    let column_name = array
+        .column_by_name("column_name")
+        .and_then(|a| a.as_any().downcast_ref::<StringArray>());
+    let ordinal_position = array
+        .column_by_name("ordinal_position")
+        .and_then(|a| a.as_any().downcast_ref::<Int32Array>());
+    let remarks = array
+        .column_by_name("remarks")
+        .and_then(|a| a.as_any().downcast_ref::<StringArray>());
+    let xdbc_type_name = array
+        .column_by_name("xdbc_type_name")
+        .and_then(|a| a.as_any().downcast_ref::<StringArray>());
+    let xdbc_column_size = array
+        .column_by_name("xdbc_column_size")
+        .and_then(|a| a.as_any().downcast_ref::<Int32Array>());
+    let xdbc_decimal_digits = array
+        .column_by_name("xdbc_decimal_digits")
+        .and_then(|a| a.as_any().downcast_ref::<Int16Array>());
+    let xdbc_num_prec_radix = array
+        .column_by_name("xdbc_num_prec_radix")
+        .and_then(|a| a.as_any().downcast_ref::<Int16Array>());
+    let xdbc_nullable = array
+        .column_by_name("xdbc_nullable")
+        .and_then(|a| a.as_any().downcast_ref::<Int16Array>());
+    let xdbc_column_def = array
+        .column_by_name("xdbc_column_def")
+        .and_then(|a| a.as_any().downcast_ref::<StringArray>());
+    let xdbc_sql_data_type = array
+        .column_by_name("xdbc_sql_data_type")
+        .and_then(|a| a.as_any().downcast_ref::<Int16Array>());
+    let xdbc_datetime_sub = array
+        .column_by_name("xdbc_datetime_sub")
+        .and_then(|a| a.as_any().downcast_ref::<Int16Array>());
+    let xdbc_char_octet_length = array
+        .column_by_name("xdbc_char_octet_length")
+        .and_then(|a| a.as_any().downcast_ref::<Int32Array>());
+    let xdbc_is_nullable = array
+        .column_by_name("xdbc_is_nullable")
+        .and_then(|a| a.as_any().downcast_ref::<StringArray>());
+    let xdbc_is_autoincrement = array
+        .column_by_name("xdbc_is_autoincrement")
+        .and_then(|a| a.as_any().downcast_ref::<BooleanArray>());
+    let xdbc_is_generatedcolumn = array
+        .column_by_name("xdbc_is_generatedcolumn")
+        .and_then(|a| a.as_any().downcast_ref::<BooleanArray>());
+    (0..array.len())
+        .map(|i| Column {
+            column_name: opt_string(column_name, i).unwrap_or_default(),
+            ordinal_position: opt_i32(ordinal_position, i),
+            remarks: opt_string(remarks, i),
+            xdbc_type_name: opt_string(xdbc_type_name, i),
+            xdbc_column_size: opt_i32(xdbc_column_size, i),
+            xdbc_decimal_digits: opt_i16(xdbc_decimal_digits, i),
+            xdbc_num_prec_radix: opt_i16(xdbc_num_prec_radix, i),
+            xdbc_nullable: opt_i16(xdbc_nullable, i),
+            xdbc_column_def: opt_string(xdbc_column_def, i),
+            xdbc_sql_data_type: opt_i16(xdbc_sql_data_type, i),
+            xdbc_datetime_sub: opt_i16(xdbc_datetime_sub, i),
+            xdbc_char_octet_length: opt_i32(xdbc_char_octet_length, i),
+            xdbc_is_nullable: opt_string(xdbc_is_nullable, i),
+            xdbc_is_autoincrement: opt_bool(xdbc_is_autoincrement, i),
+            xdbc_is_generatedcolumn: opt_bool(xdbc_is_generatedcolumn, i),
+        })
+        .collect()
+}
+
+fn decode_constraints(array: &StructArray) -> Vec<Constraint> {
+    let constraint_name = array
+        .column_by_name("constraint_name")
+        .and_then(|a| a.as_any().downcast_ref::<StringArray>());
+    let constraint_type = array
+        .column_by_name("constraint_type")
+        .and_then(|a| a.as_any().downcast_ref::<StringArray>());
+    let constraint_column_names = array
+        .column_by_name("constraint_column_names")
+        .and_then(|a| a.as_any().downcast_ref::<ListArray>());
+    let constraint_column_usage = array
+        .column_by_name("constraint_column_usage")
+        .and_then(|a| a.as_any().downcast_ref::<ListArray>());
+    (0..array.len())
+        .map(|i| Constraint {
+            constraint_name: opt_string(constraint_name, i),
+            constraint_type: opt_string(constraint_type, i).unwrap_or_default(),
+            column_names: constraint_column_names
+                .and_then(|list| nested_struct_strings(list, i))
+                .unwrap_or_default(),
+            column_usage: constraint_column_usage
+                .and_then(|list| nested_struct(list, i))
+                .map(|usage| decode_usage(&usage))
+                .unwrap_or_default(),
+        })
+        .collect()
+}
+
+/// Decode a `List<Utf8>` row into a `Vec<String>`, skipping any null
+/// entries within the list.
+fn nested_struct_strings(list: &ListArray, i: usize) -> Option<Vec<String>> {
+    if !list.is_valid(i) {
+        return None;
+    }
+    let values = list.value(i);
+    let values = values.as_any().downcast_ref::<StringArray>()?;
+    Some(
+        (0..values.len())
+            .filter_map(|j| values.is_valid(j).then(|| values.value(j).to_string()))
+            .collect(),
+    )
+}
+
+fn decode_usage(array: &StructArray) -> Vec<ConstraintUsage> {
+    let fk_catalog = array
+        .column_by_name("fk_catalog")
+        .and_then(|a| a.as_any().downcast_ref::<StringArray>());
+    let fk_db_schema = array
+        .column_by_name("fk_db_schema")
+        .and_then(|a| a.as_any().downcast_ref::<StringArray>());
+    let fk_table = array
+        .column_by_name("fk_table")
+        .and_then(|a| a.as_any().downcast_ref::<StringArray>());
+    let fk_column_name = array
+        .column_by_name("fk_column_name")
+        .and_then(|a| a.as_any().downcast_ref::<StringArray>());
+    (0..array.len())
+        .map(|i| ConstraintUsage {
+            fk_catalog: opt_string(fk_catalog, i),
+            fk_db_schema: opt_string(fk_db_schema, i),
+            fk_table: opt_string(fk_table, i).unwrap_or_default(),
+            fk_column_name: opt_string(fk_column_name, i).unwrap_or_default(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::record_batch::RecordBatchIterator;
+
+    fn sample_catalogs() -> Vec<CatalogInfo> {
+        vec![CatalogInfo {
+            catalog_name: Some("my_catalog".to_string()),
+            db_schemas: vec![DbSchemaInfo {
+                db_schema_name: Some("my_schema".to_string()),
+                tables: vec![TableInfo {
+                    table_name: "my_table".to_string(),
+                    table_type: "BASE TABLE".to_string(),
+                    columns: vec![ColumnInfo {
+                        column_name: "id".to_string(),
+                        ordinal_position: Some(1),
+                        remarks: None,
+                    }],
+                    constraints: vec![ConstraintInfo {
+                        constraint_name: Some("pk".to_string()),
+                        constraint_type: "PRIMARY KEY".to_string(),
+                        column_names: vec!["id".to_string()],
+                        usage: vec![],
+                    }],
+                }],
+            }],
+        }]
+    }
+
+    fn batch_reader(batch: RecordBatch) -> RecordBatchIterator<std::vec::IntoIter<Result<RecordBatch, ArrowError>>> {
+        let schema = batch.schema();
+        RecordBatchIterator::new(vec![Ok(batch)].into_iter(), schema)
+    }
+
+    #[test]
+    fn test_round_trip_at_depth_all() {
+        let batch = get_objects_batch(
+            AdbcObjectDepth::All,
+            None,
+            None,
+            None,
+            None,
+            None,
+            &sample_catalogs(),
+        )
+        .unwrap();
+
+        let decoded = CatalogCollection::try_from_reader(batch_reader(batch)).unwrap();
+        assert_eq!(decoded.catalogs.len(), 1);
+        let catalog = &decoded.catalogs[0];
+        assert_eq!(catalog.catalog_name.as_deref(), Some("my_catalog"));
+        assert_eq!(catalog.schemas.len(), 1);
+        let table = &catalog.schemas[0].tables[0];
+        assert_eq!(table.table_name, "my_table");
+        assert_eq!(table.columns.len(), 1);
+        assert_eq!(table.columns[0].column_name, "id");
+        assert_eq!(table.columns[0].ordinal_position, Some(1));
+        assert_eq!(table.constraints.len(), 1);
+        assert_eq!(table.constraints[0].column_names, vec!["id".to_string()]);
+    }
+
+    #[test]
+    fn test_round_trip_truncates_below_requested_depth() {
+        let batch = get_objects_batch(
+            AdbcObjectDepth::Tables,
+            None,
+            None,
+            None,
+            None,
+            None,
+            &sample_catalogs(),
+        )
+        .unwrap();
+
+        let decoded = CatalogCollection::try_from_reader(batch_reader(batch)).unwrap();
+        let table = &decoded.catalogs[0].schemas[0].tables[0];
+        assert_eq!(table.table_name, "my_table");
+        // Requested depth stopped at tables, so columns/constraints were
+        // never populated and decode to empty rather than an error.
+        assert!(table.columns.is_empty());
+        assert!(table.constraints.is_empty());
+    }
+
+    #[test]
+    fn test_like_filter_applied_to_table_name() {
+        let batch = get_objects_batch(
+            AdbcObjectDepth::Tables,
+            None,
+            None,
+            Some("other%"),
+            None,
+            None,
+            &sample_catalogs(),
+        )
+        .unwrap();
+
+        let decoded = CatalogCollection::try_from_reader(batch_reader(batch)).unwrap();
+        assert!(decoded.catalogs[0].schemas[0].tables.is_empty());
+    }
+}
@@ -0,0 +1,301 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Well-known option keys recognized by ADBC drivers.
+//!
+//! These are passed as the `key` argument to
+//! [crate::interface::DatabaseApi::set_option],
+//! [crate::interface::ConnectionApi::set_option], or
+//! [crate::interface::StatementApi::set_option].
+use std::time::Duration;
+
+use crate::interface::{ConnectionApi, DatabaseApi};
+
+/// Set the autocommit mode of a connection: `"true"` or `"false"`.
+pub const CONNECTION_OPTION_AUTOCOMMIT: &str = "adbc.connection.autocommit";
+
+/// Put a connection into read-only mode: `"true"` or `"false"`.
+pub const CONNECTION_OPTION_READ_ONLY: &str = "adbc.connection.readonly";
+
+/// Set a connection's transaction isolation level to one of the
+/// `adbc.connection.transaction.isolation.*` values below.
+pub const CONNECTION_OPTION_ISOLATION_LEVEL: &str = "adbc.connection.transaction.isolation_level";
+
+/// The `sqlite` driver's PRAGMA for foreign key enforcement: `"true"` or
+/// `"false"`.
+pub const CONNECTION_OPTION_SQLITE_PRAGMA_FOREIGN_KEYS: &str = "adbc.sqlite.pragma.foreign_keys";
+
+/// The `sqlite` driver's PRAGMA for the busy timeout, in milliseconds.
+pub const CONNECTION_OPTION_SQLITE_PRAGMA_BUSY_TIMEOUT: &str = "adbc.sqlite.pragma.busy_timeout";
+
+/// Transaction isolation levels recognized by
+/// [AdbcOptionsBuilder::isolation_level], matching the
+/// `adbc.connection.transaction.isolation.*` values documented on
+/// [adbc.h](https://github.com/apache/arrow-adbc/blob/main/adbc.h).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IsolationLevel {
+    /// Use the database's default isolation level.
+    Default,
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Snapshot,
+    Serializable,
+    Linearizable,
+}
+
+impl IsolationLevel {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Default => "adbc.connection.transaction.isolation.default",
+            Self::ReadUncommitted => "adbc.connection.transaction.isolation.read_uncommitted",
+            Self::ReadCommitted => "adbc.connection.transaction.isolation.read_committed",
+            Self::RepeatableRead => "adbc.connection.transaction.isolation.repeatable_read",
+            Self::Snapshot => "adbc.connection.transaction.isolation.snapshot",
+            Self::Serializable => "adbc.connection.transaction.isolation.serializable",
+            Self::Linearizable => "adbc.connection.transaction.isolation.linearizable",
+        }
+    }
+}
+
+fn bool_str(value: bool) -> &'static str {
+    if value {
+        "true"
+    } else {
+        "false"
+    }
+}
+
+/// A typed builder for the common `adbc.*`/driver options, lowering each
+/// call to the `(key, value)` string pair [DatabaseApi::set_option] and
+/// [ConnectionApi::set_option] expect.
+///
+/// This covers the well-known knobs with compile-time-checked Rust values
+/// (so a typo like `"autocomit"` is no longer a silent, do-nothing runtime
+/// error); [Self::raw] remains available as an escape hatch for
+/// driver-specific options this builder does not model.
+#[derive(Debug, Clone, Default)]
+pub struct AdbcOptionsBuilder {
+    pairs: Vec<(String, String)>,
+}
+
+impl AdbcOptionsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set [CONNECTION_OPTION_AUTOCOMMIT].
+    pub fn autocommit(self, autocommit: bool) -> Self {
+        self.raw(CONNECTION_OPTION_AUTOCOMMIT, bool_str(autocommit))
+    }
+
+    /// Set [CONNECTION_OPTION_READ_ONLY].
+    pub fn read_only(self, read_only: bool) -> Self {
+        self.raw(CONNECTION_OPTION_READ_ONLY, bool_str(read_only))
+    }
+
+    /// Set [CONNECTION_OPTION_ISOLATION_LEVEL].
+    pub fn isolation_level(self, level: IsolationLevel) -> Self {
+        self.raw(CONNECTION_OPTION_ISOLATION_LEVEL, level.as_str())
+    }
+
+    /// Set [CONNECTION_OPTION_SQLITE_PRAGMA_FOREIGN_KEYS].
+    pub fn sqlite_foreign_keys(self, enabled: bool) -> Self {
+        self.raw(CONNECTION_OPTION_SQLITE_PRAGMA_FOREIGN_KEYS, bool_str(enabled))
+    }
+
+    /// Set [CONNECTION_OPTION_SQLITE_PRAGMA_BUSY_TIMEOUT].
+    pub fn sqlite_busy_timeout(self, timeout: Duration) -> Self {
+        self.raw(
+            CONNECTION_OPTION_SQLITE_PRAGMA_BUSY_TIMEOUT,
+            timeout.as_millis().to_string(),
+        )
+    }
+
+    /// Escape hatch for a driver-specific option this builder doesn't model.
+    pub fn raw(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.pairs.push((key.into(), value.into()));
+        self
+    }
+
+    /// Apply every configured option to `connection` via
+    /// [ConnectionApi::set_option], in the order they were set, stopping at
+    /// the first error.
+    pub fn apply_to_connection<C: ConnectionApi>(&self, connection: &C) -> Result<(), C::Error> {
+        for (key, value) in &self.pairs {
+            connection.set_option(key, value)?;
+        }
+        Ok(())
+    }
+
+    /// Apply every configured option to `database` via
+    /// [DatabaseApi::set_option], in the order they were set, stopping at
+    /// the first error.
+    pub fn apply_to_database<D: DatabaseApi>(&self, database: &D) -> Result<(), D::Error> {
+        for (key, value) in &self.pairs {
+            database.set_option(key, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Set the target table for a bulk ingestion via [crate::interface::StatementApi::bind_data]
+/// followed by [crate::interface::StatementApi::execute_update].
+pub const INGEST_OPTION_TARGET_TABLE: &str = "adbc.ingest.target_table";
+
+/// The ingestion mode: `"adbc.ingest.mode.create"` (the default) or
+/// `"adbc.ingest.mode.append"`.
+pub const INGEST_OPTION_MODE: &str = "adbc.ingest.mode";
+
+/// Create the table; error if it already exists.
+pub const INGEST_OPTION_MODE_CREATE: &str = "adbc.ingest.mode.create";
+
+/// Append to the table; error if it does not exist.
+pub const INGEST_OPTION_MODE_APPEND: &str = "adbc.ingest.mode.append";
+
+/// Comma-separated primary-key columns used to coalesce a versioned change
+/// stream bound via [crate::interface::StatementApi::bind_change_stream],
+/// e.g. `"id"` or `"tenant_id,id"`.
+///
+/// This is consumed locally by the driver manager's
+/// [crate::driver_manager::AdbcStatement] and is never forwarded to the
+/// driver.
+pub const INGEST_OPTION_KEY_COLUMNS: &str = "adbc.ingest.key_columns";
+
+/// The number of buffered row-versions from a change stream bound via
+/// [crate::interface::StatementApi::bind_change_stream] (see
+/// [INGEST_OPTION_KEY_COLUMNS]) after which they are coalesced down to the
+/// latest version per key, bounding memory on a long-running stream.
+///
+/// This is consumed locally by the driver manager's
+/// [crate::driver_manager::AdbcStatement] and is never forwarded to the
+/// driver.
+pub const INGEST_OPTION_MAX_BUFFERED_VERSIONS: &str = "adbc.ingest.max_buffered_versions";
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct TestError(String);
+
+    #[derive(Default)]
+    struct RecordingTarget {
+        options: RefCell<Vec<(String, String)>>,
+        fail_on: Option<&'static str>,
+    }
+
+    impl RecordingTarget {
+        fn record(&self, key: &str, value: &str) -> std::result::Result<(), TestError> {
+            if self.fail_on == Some(key) {
+                return Err(TestError(format!("rejected {key}")));
+            }
+            self.options
+                .borrow_mut()
+                .push((key.to_string(), value.to_string()));
+            Ok(())
+        }
+    }
+
+    impl ConnectionApi for RecordingTarget {
+        type Error = TestError;
+
+        fn set_option(&self, key: &str, value: &str) -> std::result::Result<(), TestError> {
+            self.record(key, value)
+        }
+    }
+
+    impl DatabaseApi for RecordingTarget {
+        type Error = TestError;
+
+        fn set_option(&self, key: &str, value: &str) -> std::result::Result<(), TestError> {
+            self.record(key, value)
+        }
+    }
+
+    #[test]
+    fn test_applies_options_in_order() {
+        let builder = AdbcOptionsBuilder::new()
+            .autocommit(false)
+            .read_only(true)
+            .isolation_level(IsolationLevel::Serializable)
+            .sqlite_foreign_keys(true)
+            .sqlite_busy_timeout(Duration::from_millis(500))
+            .raw("adbc.driver.custom_option", "custom_value");
+
+        let target = RecordingTarget::default();
+        builder.apply_to_connection(&target).unwrap();
+
+        assert_eq!(
+            *target.options.borrow(),
+            vec![
+                (CONNECTION_OPTION_AUTOCOMMIT.to_string(), "false".to_string()),
+                (CONNECTION_OPTION_READ_ONLY.to_string(), "true".to_string()),
+                (
+                    CONNECTION_OPTION_ISOLATION_LEVEL.to_string(),
+                    "adbc.connection.transaction.isolation.serializable".to_string()
+                ),
+                (
+                    CONNECTION_OPTION_SQLITE_PRAGMA_FOREIGN_KEYS.to_string(),
+                    "true".to_string()
+                ),
+                (
+                    CONNECTION_OPTION_SQLITE_PRAGMA_BUSY_TIMEOUT.to_string(),
+                    "500".to_string()
+                ),
+                (
+                    "adbc.driver.custom_option".to_string(),
+                    "custom_value".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_apply_to_database_uses_database_api() {
+        let builder = AdbcOptionsBuilder::new().autocommit(true);
+        let target = RecordingTarget::default();
+        builder.apply_to_database(&target).unwrap();
+        assert_eq!(
+            *target.options.borrow(),
+            vec![(CONNECTION_OPTION_AUTOCOMMIT.to_string(), "true".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_stops_at_first_error() {
+        let builder = AdbcOptionsBuilder::new()
+            .autocommit(true)
+            .read_only(true)
+            .isolation_level(IsolationLevel::Default);
+
+        let target = RecordingTarget {
+            fail_on: Some(CONNECTION_OPTION_READ_ONLY),
+            ..Default::default()
+        };
+        let err = builder.apply_to_connection(&target).unwrap_err();
+        assert_eq!(err, TestError(format!("rejected {CONNECTION_OPTION_READ_ONLY}")));
+
+        // Only the option before the failing one was applied.
+        assert_eq!(
+            *target.options.borrow(),
+            vec![(CONNECTION_OPTION_AUTOCOMMIT.to_string(), "true".to_string())]
+        );
+    }
+}
@@ -0,0 +1,48 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Decoding for [Substrait](https://substrait.io) query plans, for drivers
+//! that accept them via [crate::interface::StatementApi::set_substrait_plan].
+//!
+//! This module requires the `substrait` feature. It does not attempt to
+//! model Substrait's full relational algebra; it exposes just enough of the
+//! `substrait.Plan` message (via [prost]) for a driver to read a plan's
+//! extension URIs and top-level relations without hand-rolling protobuf
+//! parsing. Drivers that need the complete schema should depend on the
+//! upstream `substrait` crate directly and decode the raw bytes themselves.
+use prost::Message;
+
+/// A `substrait.Plan` message, as passed to
+/// [crate::interface::StatementApi::set_substrait_plan].
+#[derive(Clone, PartialEq, Message)]
+pub struct Plan {
+    /// URIs of the extensions (scalar/aggregate functions, types) this
+    /// plan's relations reference.
+    #[prost(string, repeated, tag = "1")]
+    pub extension_uris: Vec<String>,
+    /// The plan's top-level relations, left as opaque encoded bytes.
+    #[prost(bytes = "vec", repeated, tag = "4")]
+    pub relations: Vec<Vec<u8>>,
+}
+
+impl Plan {
+    /// Decode a plan from the bytes a caller passed to
+    /// [crate::interface::StatementApi::set_substrait_plan].
+    pub fn decode_bytes(plan: &[u8]) -> Result<Self, prost::DecodeError> {
+        Self::decode(plan)
+    }
+}
@@ -0,0 +1,51 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Run the starter conformance scripts in `tests/conformance/` against the
+//! SQLite driver.
+
+use arrow_adbc::conformance::{run_script, Script};
+use arrow_adbc::driver_manager::AdbcDriver;
+use arrow_adbc::ADBC_VERSION_1_0_0;
+
+fn get_driver() -> AdbcDriver {
+    AdbcDriver::load("adbc_driver_sqlite", None, ADBC_VERSION_1_0_0).unwrap()
+}
+
+fn run(path: &str) {
+    let text = std::fs::read_to_string(path).unwrap();
+    let script = Script::from_json(&text).unwrap();
+    let driver = get_driver();
+    if let Err(failure) = run_script(&driver, &script) {
+        panic!("{path}: {failure}");
+    }
+}
+
+#[test]
+fn test_basic_select() {
+    run("tests/conformance/basic_select.json");
+}
+
+#[test]
+fn test_missing_table() {
+    run("tests/conformance/missing_table.json");
+}
+
+#[test]
+fn test_ingest_round_trip() {
+    run("tests/conformance/ingest_round_trip.json");
+}